@@ -1,98 +1,198 @@
 use std::char::from_u32;
-use std::collections::HashSet;
 use std::io::prelude::*;
 use std::io::{self, BufWriter};
 
 mod trie;
 use trie::Trie;
 
-const DGC: &'static str = include_str!("../dat/DerivedGeneralCategory.txt");
-const CATEGORY_NAMES: [&'static str; 30] = [
-    "Lu", "Ll", "Lt", "Lm", "Lo",
-    "Mn", "Mc", "Me",
-    "Nd", "Nl", "No",
-    "Pc", "Pd", "Ps", "Pe", "Pi", "Pf", "Po",
-    "Sm", "Sc", "Sk", "So",
-    "Zs", "Zl", "Zp",
-    "Cc", "Cf", "Cs", "Co", "Cn"
-];
+const PVA: &str = include_str!("../dat/PropertyValueAliases.txt");
+const DGC: &str = include_str!("../dat/DerivedGeneralCategory.txt");
+const SCR: &str = include_str!("../dat/Scripts.txt");
+const SCX: &str = include_str!("../dat/ScriptExtensions.txt");
 
-const SCR: &'static str = include_str!("../dat/Scripts.txt");
-const SCRIPT_NAMES: [&'static str; 149] = [
-    "Unknown", "Adlam", "Ahom", "Anatolian_Hieroglyphs", "Arabic", "Armenian", "Avestan", 
-    "Balinese", "Bamum", "Bassa_Vah", "Batak", "Bengali", "Bhaiksuki", "Bopomofo", 
-    "Brahmi", "Braille", "Buginese", "Buhid", "Canadian_Aboriginal", "Carian", "Caucasian_Albanian", 
-    "Chakma", "Cham", "Cherokee", "Common", "Coptic", "Cuneiform", "Cypriot", "Cyrillic", 
-    "Deseret", "Devanagari", "Dogra", "Duployan", "Egyptian_Hieroglyphs", "Elbasan", 
-    "Ethiopic", "Georgian", "Glagolitic", "Gothic", "Grantha", "Greek", "Gujarati", 
-    "Gunjala_Gondi", "Gurmukhi", "Han", "Hangul", "Hanifi_Rohingya", "Hanunoo", "Hatran", 
-    "Hebrew", "Hiragana", "Imperial_Aramaic", "Inherited", "Inscriptional_Pahlavi", 
-    "Inscriptional_Parthian", "Javanese", "Kaithi", "Kannada", "Katakana", "Kayah_Li", 
-    "Kharoshthi", "Khmer", "Khojki", "Khudawadi", "Lao", "Latin", "Lepcha", "Limbu", 
-    "Linear_A", "Linear_B", "Lisu", "Lycian", "Lydian", "Mahajani", "Makasar", "Malayalam", 
-    "Mandaic", "Manichaean", "Marchen", "Masaram_Gondi", "Medefaidrin", "Meetei_Mayek", 
-    "Mende_Kikakui", "Meroitic_Cursive", "Meroitic_Hieroglyphs", "Miao", "Modi", 
-    "Mongolian", "Mro", "Multani", "Myanmar", "Nabataean", "New_Tai_Lue", "Newa", 
-    "Nko", "Nushu", "Ogham", "Ol_Chiki", "Old_Hungarian", "Old_Italic", "Old_North_Arabian", 
-    "Old_Permic", "Old_Persian", "Old_Sogdian", "Old_South_Arabian", "Old_Turkic", 
-    "Oriya", "Osage", "Osmanya", "Pahawh_Hmong", "Palmyrene", "Pau_Cin_Hau", "Phags_Pa", 
-    "Phoenician", "Psalter_Pahlavi", "Rejang", "Runic", "Samaritan", "Saurashtra", 
-    "Sharada", "Shavian", "Siddham", "SignWriting", "Sinhala", "Sogdian", "Sora_Sompeng", 
-    "Soyombo", "Sundanese", "Syloti_Nagri", "Syriac", "Tagalog", "Tagbanwa", "Tai_Le", 
-    "Tai_Tham", "Tai_Viet", "Takri", "Tamil", "Tangut", "Telugu", "Thaana", "Thai", 
-    "Tibetan", "Tifinagh", "Tirhuta", "Ugaritic", "Vai", "Warang_Citi", "Yi", "Zanabazar_Square", 
+// The bits the general categories have always had, which users may have
+// stored or matched on. Categories added by later Unicode versions go after.
+const CATEGORY_BITS: &[&str] = &[
+    "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe",
+    "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co", "Cn",
 ];
 
+// A property value from PropertyValueAliases.txt, with the short values it
+// groups together if it is a General_Category group like `L`.
+struct Alias<'a> {
+    short: &'a str,
+    long: &'a str,
+    members: Vec<&'a str>,
+}
+
+fn aliases<'a>(data: &'a str, property: &str) -> Vec<Alias<'a>> {
+    let mut values = Vec::new();
+    for l in data.lines() {
+        let (s, comment) = split2(l, "#").unwrap_or((l, ""));
+        let fields: Vec<&str> = s.split(';').map(str::trim).collect();
+        if fields.len() < 3 || fields[0] != property { continue; }
+        
+        let members = comment.split('|').map(str::trim).filter(|m| !m.is_empty()).collect();
+        values.push(Alias { short: fields[1], long: fields[2], members });
+    }
+    
+    values
+}
+
+// `Old_Italic` becomes `OldItalic` as a variant, or `OLD_ITALIC` as a constant.
+fn variant_name(long: &str) -> String {
+    long.replace('_', "")
+}
+
+fn const_name(long: &str) -> String {
+    long.to_uppercase()
+}
+
 fn split2<'a>(s: &'a str, sep: &str) -> Option<(&'a str, &'a str)> {
     let mut s = s.splitn(2, sep);
     s.next().and_then(|one| s.next().map(|two| (one, two)))
 }
 
-fn main() {
-    let mut cats = Trie::new();
-    for l in DGC.lines() {
+fn load<F: FnMut(&str) -> u64>(data: &str, mut value_of: F) -> Vec<u64> {
+    let mut values = vec![0; 0x110000];
+    for l in data.lines() {
         let s = l[..l.find('#').unwrap_or(l.len())].trim();
-        if s.len() == 0 { continue; }
+        if s.is_empty() { continue; }
         
         let (range, value) = split2(s, ";").unwrap();
-        let (range, value) = (range.trim(), value.trim());
+        let (range, value) = (range.trim(), value_of(value.trim()));
         
-        let value = CATEGORY_NAMES.iter().position(|&s| s == value).unwrap();
         let (start, end) = split2(range, "..").unwrap_or((range, range));
         
         let start = u32::from_str_radix(start, 16).unwrap();
         let end   = u32::from_str_radix(end,   16).unwrap();
         
         for c in start..end+1 {
-            if let Some(c) = from_u32(c) {
-                cats.insert(c, value as u64);
-            }
+            values[c as usize] = value;
         }
     }
     
-    let mut scripts = Trie::new();
-    for l in SCR.lines() {
-        let s = l[..l.find('#').unwrap_or(l.len())].trim();
-        if s.len() == 0 { continue; }
-        
-        let (range, value) = split2(s, ";").unwrap();
-        let (range, value) = (range.trim(), value.trim());
-        
-        let value = SCRIPT_NAMES.iter().position(|&s| s == value).unwrap();
-        let (start, end) = split2(range, "..").unwrap_or((range, range));
-        
-        let start = u32::from_str_radix(start, 16).unwrap();
-        let end   = u32::from_str_radix(end,   16).unwrap();
+    values
+}
+
+fn index_of(names: &[&str], value: &str) -> u64 {
+    names.iter().position(|&s| s == value).unwrap() as u64
+}
+
+// Code points whose Script_Extensions differ from their Script get the index
+// of their set plus one; all others get zero.
+fn load_script_sets(data: &str, codes: &[&str]) -> (Vec<u64>, Vec<Vec<u64>>) {
+    let mut sets = Vec::new();
+    let values = load(data, |value| {
+        let mut set = vec![0; codes.len().div_ceil(64)];
+        for code in value.split_whitespace() {
+            let s = index_of(codes, code);
+            set[(s / 64) as usize] |= 1 << (s % 64);
+        }
         
-        for c in start..end+1 {
-            if let Some(c) = from_u32(c) {
-                scripts.insert(c, value as u64);
-            }
+        let id = sets.iter().position(|x| *x == set).unwrap_or_else(|| {
+            sets.push(set);
+            sets.len() - 1
+        });
+        id as u64 + 1
+    });
+    
+    (values, sets)
+}
+
+// The version from a UCD file's first line, e.g. `# Scripts-17.0.0.txt`
+fn version(data: &str) -> Vec<u8> {
+    let first = data.lines().next().unwrap();
+    let name = &first[first.rfind('-').unwrap() + 1..first.rfind(".txt").unwrap()];
+    name.split('.').map(|n| n.parse().unwrap()).collect()
+}
+
+fn build(values: &[u64]) -> Trie {
+    let mut trie = Trie::new();
+    for (c, &value) in values.iter().enumerate() {
+        if let Some(c) = from_u32(c as u32) {
+            trie.insert(c, value);
         }
     }
     
+    trie
+}
+
+fn main() {
+    let v = version(DGC);
+    assert!(version(PVA) == v && version(SCR) == v && version(SCX) == v,
+        "data files are from different Unicode versions");
+    
+    // Groups like `L` come after the values they contain, which get one bit
+    // each in the order of `CATEGORY_BITS`.
+    let cat_aliases = aliases(PVA, "gc");
+    let (groups, mut single): (Vec<_>, Vec<_>) = cat_aliases.iter().partition(|a| !a.members.is_empty());
+    single.sort_by_key(|a| CATEGORY_BITS.iter().position(|&s| s == a.short).unwrap_or(CATEGORY_BITS.len()));
+    let cat_names: Vec<&str> = single.iter().map(|a| a.short).collect();
+    
+    // Unknown comes first, since code points missing from Scripts.txt get
+    // the value 0.
+    let mut script_aliases = aliases(PVA, "sc");
+    script_aliases.sort_by_key(|a| (a.long != "Unknown", a.long));
+    let script_names: Vec<&str> = script_aliases.iter().map(|a| a.long).collect();
+    let script_codes: Vec<&str> = script_aliases.iter().map(|a| a.short).collect();
+    
+    let cats = load(DGC, |v| index_of(&cat_names, v));
+    let scripts = load(SCR, |v| index_of(&script_names, v));
+    let (scx, scx_sets) = load_script_sets(SCX, &script_codes);
+    
+    // Both properties in one value, with the category in the low five bits,
+    // so that decoding them together takes a single trie walk.
+    assert!(cat_names.len() <= 32 && script_names.len() <= 256);
+    let props: Vec<u64> = cats.iter().zip(&scripts).map(|(&c, &s)| c | s << 5).collect();
+    
     let mut f = BufWriter::new(io::stdout());
     writeln!(f, "// \n// Generated code file\n// \n").expect("io error");
-    cats.write_tables("CAT", "u16", &mut f);
-    scripts.write_tables("SCRIPT", "u16", &mut f);
+    writeln!(f, "use decode_utf8::GeneralCategory;\n").expect("io error");
+    writeln!(f, "/// The version of the Unicode Character Database the tables are generated from.")
+        .expect("io error");
+    writeln!(f, "pub const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});\n", v[0], v[1], v[2])
+        .expect("io error");
+    
+    for (i, a) in single.iter().enumerate() {
+        writeln!(f, "pub const {}: GeneralCategory = 1 << {};", const_name(a.long), i).expect("io error");
+    }
+    for a in groups.iter() {
+        let members: Vec<String> = a.members.iter()
+            .map(|&m| const_name(single.iter().find(|s| s.short == m).unwrap().long))
+            .collect();
+        writeln!(f, "pub const {}: GeneralCategory =\n    {};", const_name(a.long), members.join(" | "))
+            .expect("io error");
+    }
+    
+    writeln!(f, "\npub(crate) const CATEGORY_NAMES: &[(&str, &str, GeneralCategory)] = &[").expect("io error");
+    for a in cat_aliases.iter() {
+        writeln!(f, "    (\"{}\", \"{}\", {}),", a.short, a.long, const_name(a.long)).expect("io error");
+    }
+    writeln!(f, "];\n").expect("io error");
+    
+    writeln!(f, "#[derive(Clone, Copy, Debug, PartialEq)]\n#[repr(u8)]\npub enum Script {{").expect("io error");
+    for a in script_aliases.iter() {
+        writeln!(f, "    {},", variant_name(a.long)).expect("io error");
+    }
+    writeln!(f, "}}\n").expect("io error");
+    
+    writeln!(f, "pub(crate) const SCRIPT_NAMES: &[(&str, &str)] = &[").expect("io error");
+    for a in script_aliases.iter() {
+        writeln!(f, "    (\"{}\", \"{}\"),", a.short, a.long).expect("io error");
+    }
+    writeln!(f, "];\n").expect("io error");
+    
+    build(&cats).write_tables("CAT", "u16", &mut f);
+    build(&scripts).write_tables("SCRIPT", "u16", &mut f);
+    build(&props).write_tables("PROPS", "u16", &mut f);
+    build(&scx).write_tables("SCX", "u16", &mut f);
+    
+    let words = script_names.len().div_ceil(64);
+    writeln!(f, "\npub(crate) const SCX_SETS: &[[u64; {}]] = &[", words).expect("io error");
+    for set in scx_sets.iter() {
+        let words: Vec<String> = set.iter().map(|w| format!("0x{:016x}", w)).collect();
+        writeln!(f, "[{}],", words.join(", ")).expect("io error");
+    }
+    writeln!(f, "];").expect("io error");
 }
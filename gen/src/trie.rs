@@ -121,7 +121,7 @@ impl Trie {
             value_block_idx: FromIterator::from_iter([(0, 0)].iter().map(|&x|x)),
         };
         b.build();
-        writeln!(f, "pub const {}_VALUES: &[{}] = &[", name, b.value_type).expect("io error");
+        writeln!(f, "pub(crate) const {}_VALUES: &[{}] = &[", name, b.value_type).expect("io error");
         let mut w = 0;
         for (_, v) in b.value_blocks.iter().enumerate() {
             for (_, &x) in v.iter().enumerate() {
@@ -133,7 +133,7 @@ impl Trie {
             }
         }
         writeln!(f, "\n];\n").expect("io error");
-        writeln!(f, "pub const {}_INDEX: &[{}] = &[", name, index_type).expect("io error");
+        writeln!(f, "pub(crate) const {}_INDEX: &[{}] = &[", name, index_type).expect("io error");
         let mut w = 0;
         for (_, c) in b.index_blocks.iter().enumerate() {
             for (_, x) in c.borrow().children.as_ref()
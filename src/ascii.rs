@@ -0,0 +1,83 @@
+// 
+// Vectorized ASCII Scanning
+// 
+// ASCII bytes are exactly those the DFA accepts from the initial state without
+// leaving it, so skipping a run of them is always consistent with decoding it
+// byte by byte. These functions find the length of such a run, 16 or 32 bytes
+// at a time where the target supports it.
+// 
+
+use core::mem::size_of;
+
+#[cfg(target_arch = "x86_64")]
+pub fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    if bytes.len() >= 32 && has_avx2() {
+        unsafe { ascii_prefix_len_avx2(bytes) }
+    } else {
+        unsafe { ascii_prefix_len_sse2(bytes) }
+    }
+}
+
+// Without std there is no runtime feature detection, so AVX2 is only used if
+// the build targets it anyway. SSE2 is part of the x86_64 baseline.
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn has_avx2() -> bool {
+    is_x86_feature_detected!("avx2")
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn has_avx2() -> bool {
+    cfg!(target_feature = "avx2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    ascii_prefix_len_portable(bytes)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn ascii_prefix_len_avx2(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::*;
+    
+    let mut i = 0;
+    while i + 32 <= bytes.len() {
+        let v = _mm256_loadu_si256(bytes.as_ptr().add(i) as * const __m256i);
+        let mask = _mm256_movemask_epi8(v);
+        if mask != 0 { return i + mask.trailing_zeros() as usize; }
+        i += 32;
+    }
+    
+    i + ascii_prefix_len_sse2(&bytes[i..])
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn ascii_prefix_len_sse2(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::*;
+    
+    let mut i = 0;
+    while i + 16 <= bytes.len() {
+        let v = _mm_loadu_si128(bytes.as_ptr().add(i) as * const __m128i);
+        let mask = _mm_movemask_epi8(v);
+        if mask != 0 { return i + mask.trailing_zeros() as usize; }
+        i += 16;
+    }
+    
+    i + ascii_prefix_len_portable(&bytes[i..])
+}
+
+fn ascii_prefix_len_portable(bytes: &[u8]) -> usize {
+    const WORD: usize = size_of::<usize>();
+    const HIGH_BITS: usize = usize::MAX / 0xFF * 0x80;
+    
+    let mut i = 0;
+    while i + WORD <= bytes.len() {
+        let mut word = [0; WORD];
+        word.copy_from_slice(&bytes[i..i + WORD]);
+        if usize::from_ne_bytes(word) & HIGH_BITS != 0 { break; }
+        i += WORD;
+    }
+    
+    i + bytes[i..].iter().position(|&b| b >= 0x80).unwrap_or(bytes.len() - i)
+}
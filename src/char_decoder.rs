@@ -0,0 +1,67 @@
+use decode_single_byte::{SingleByteDecoder, SingleByteDecoderPosition};
+use decode_utf16::{Utf16Decoder, Utf16DecoderPosition, Utf16Error};
+use decode_utf32::{Utf32Decoder, Utf32DecoderPosition, Utf32Error};
+use decode_utf8::{GeneralCategory, Script, Utf8Decoder, Utf8DecoderPosition, Utf8Error};
+
+use core::convert::Infallible;
+
+/// The surface shared by all decoders, so lexers can be written once and
+/// monomorphized for each input encoding.
+pub trait CharDecoder {
+    type Position: Copy;
+    type Error: Copy;
+    
+    fn next_char(&mut self) -> Option<char>;
+    fn next_char_and_category(&mut self) -> Option<(char, GeneralCategory)>;
+    fn next_char_and_script(&mut self) -> Option<(char, Script)>;
+    fn mark(&self) -> Self::Position;
+    fn status(&self) -> Result<(), Self::Error>;
+}
+
+macro_rules! delegate_char_decoder {
+    ($position:ty, $error:ty) => {
+        type Position = $position;
+        type Error = $error;
+        
+        #[inline]
+        fn next_char(&mut self) -> Option<char> {
+            self.next_char()
+        }
+        
+        #[inline]
+        fn next_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+            self.next_char_and_category()
+        }
+        
+        #[inline]
+        fn next_char_and_script(&mut self) -> Option<(char, Script)> {
+            self.next_char_and_script()
+        }
+        
+        #[inline]
+        fn mark(&self) -> $position {
+            self.mark()
+        }
+        
+        #[inline]
+        fn status(&self) -> Result<(), $error> {
+            self.status()
+        }
+    }
+}
+
+impl<'a> CharDecoder for Utf8Decoder<'a> {
+    delegate_char_decoder!(Utf8DecoderPosition<'a>, Utf8Error);
+}
+
+impl<'a> CharDecoder for Utf16Decoder<'a> {
+    delegate_char_decoder!(Utf16DecoderPosition, Utf16Error);
+}
+
+impl<'a> CharDecoder for Utf32Decoder<'a> {
+    delegate_char_decoder!(Utf32DecoderPosition, Utf32Error);
+}
+
+impl<'a> CharDecoder for SingleByteDecoder<'a> {
+    delegate_char_decoder!(SingleByteDecoderPosition, Infallible);
+}
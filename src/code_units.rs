@@ -0,0 +1,75 @@
+// 
+// Fixed-Width Code Unit Decoders
+// 
+// The UTF-16 and UTF-32 decoders only differ in how they read a character and
+// in what can go wrong doing so. Each provides a `decode` method that consumes
+// one character or ill-formed sequence and returns it or its error kind; this
+// generates everything else around it.
+// 
+
+macro_rules! code_unit_decoder {
+    ($decoder:ident, $position:ident, $error:ident, $kind:ident) => {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $position {
+            offset: usize,
+        }
+        
+        impl $position {
+            /// The byte offset into the decoded input.
+            pub fn offset(&self) -> usize {
+                self.offset
+            }
+        }
+        
+        impl<'a> $decoder<'a> {
+            pub fn status(&self) -> Result<(), $error> {
+                self.status
+            }
+            
+            pub fn set_error_mode(&mut self, mode: ErrorMode) {
+                self.mode = mode;
+            }
+            
+            pub fn next_char(&mut self) -> Option<char> {
+                self.next_with(|c| c, REPLACEMENT_CHARACTER)
+            }
+            
+            pub fn next_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+                self.next_with(|c| (c, general_category(c)), (REPLACEMENT_CHARACTER, OTHER_SYMBOL))
+            }
+            
+            pub fn next_char_and_script(&mut self) -> Option<(char, Script)> {
+                self.next_with(|c| (c, script(c)), (REPLACEMENT_CHARACTER, Script::Common))
+            }
+            
+            pub fn mark(&self) -> $position {
+                $position { offset: self.next }
+            }
+            
+            /// The raw bytes decoded since `mark` was taken.
+            pub fn get_marked_bytes(&self, mark: $position) -> &'a [u8] {
+                &self.raw[mark.offset..self.next]
+            }
+            
+            #[inline(always)]
+            fn next_with<T, F: FnOnce(char) -> T>(&mut self, f: F, replacement: T) -> Option<T> {
+                if self.next >= self.raw.len() { return None; }
+                
+                let seq = self.next;
+                match self.decode() {
+                    Ok(c) => Some(f(c)),
+                    Err(kind) => self.set_err(seq, kind, replacement),
+                }
+            }
+            
+            fn set_err<T>(&mut self, seq: usize, kind: $kind, replacement: T) -> Option<T> {
+                self.status = Err($error { kind, offset: seq, len: self.next - seq });
+                
+                match self.mode {
+                    ErrorMode::Report => None,
+                    ErrorMode::Replace | ErrorMode::Escape => Some(replacement),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use core::convert::Infallible;
+
+use decode_utf8::{general_category, script, GeneralCategory, Script};
+
+/// Decodes legacy single-byte text. Every byte maps to exactly one character,
+/// so decoding cannot fail.
+#[derive(Clone)]
+pub struct SingleByteDecoder<'a> {
+    /// What bytes 0x80 to 0x9F map to, if not to the code point of the same value.
+    high: Option<&'static [char; 32]>,
+    raw: &'a [u8],
+    next: usize,
+}
+
+/// ISO-8859-1, mapping every byte to the code point of the same value.
+pub fn decode_latin1(raw: &[u8]) -> SingleByteDecoder<'_> {
+    SingleByteDecoder { high: None, raw, next: 0 }
+}
+
+/// Windows-1252, as specified by the WHATWG Encoding Standard. The five bytes
+/// the code page leaves undefined decode to the C1 controls of the same value.
+pub fn decode_windows1252(raw: &[u8]) -> SingleByteDecoder<'_> {
+    SingleByteDecoder { high: Some(&WINDOWS1252_80_9F), raw, next: 0 }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SingleByteDecoderPosition {
+    offset: usize,
+}
+
+impl SingleByteDecoderPosition {
+    /// The byte offset into the decoded input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'a> SingleByteDecoder<'a> {
+    /// Always `Ok`; provided for parity with the other decoders.
+    pub fn status(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+    
+    pub fn next_char(&mut self) -> Option<char> {
+        let byte = *self.raw.get(self.next)?;
+        self.next += 1;
+        
+        Some(match (byte, self.high) {
+            (0x80..=0x9F, Some(high)) => high[byte as usize - 0x80],
+            _ => char::from(byte),
+        })
+    }
+    
+    pub fn next_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+        self.next_char().map(|c| (c, general_category(c)))
+    }
+    
+    pub fn next_char_and_script(&mut self) -> Option<(char, Script)> {
+        self.next_char().map(|c| (c, script(c)))
+    }
+    
+    pub fn mark(&self) -> SingleByteDecoderPosition {
+        SingleByteDecoderPosition { offset: self.next }
+    }
+    
+    /// The raw bytes decoded since `mark` was taken.
+    pub fn get_marked_bytes(&self, mark: SingleByteDecoderPosition) -> &'a [u8] {
+        &self.raw[mark.offset..self.next]
+    }
+}
+
+const WINDOWS1252_80_9F: [char; 32] = [
+    '\u{20AC}', '\u{81}',   '\u{201A}', '\u{192}',  '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}',  '\u{2030}', '\u{160}',  '\u{2039}', '\u{152}',  '\u{8D}',   '\u{17D}',  '\u{8F}',
+    '\u{90}',   '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}',  '\u{2122}', '\u{161}',  '\u{203A}', '\u{153}',  '\u{9D}',   '\u{17E}',  '\u{178}',
+];
@@ -0,0 +1,116 @@
+use core::char::{from_u32_unchecked, REPLACEMENT_CHARACTER};
+use core::fmt;
+
+use decode_utf8::{general_category, script, ErrorMode, GeneralCategory, Script, OTHER_SYMBOL};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Utf16ErrorKind {
+    /// A low surrogate without a preceding high surrogate, or a high surrogate
+    /// not followed by a low surrogate.
+    UnpairedSurrogate,
+    /// An odd number of bytes, or a high surrogate in the last code unit.
+    UnexpectedEndOfBuffer,
+}
+
+/// An ill-formed code unit sequence, located by its byte offset into the
+/// decoded input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utf16Error {
+    kind: Utf16ErrorKind,
+    offset: usize,
+    len: usize,
+}
+
+impl Utf16Error {
+    pub fn kind(&self) -> Utf16ErrorKind {
+        self.kind
+    }
+    
+    /// The byte offset at which the ill-formed sequence starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    
+    /// The length of the ill-formed sequence in bytes, or `None` if the input
+    /// ended in the middle of a code unit or surrogate pair.
+    pub fn error_len(&self) -> Option<usize> {
+        match self.kind {
+            Utf16ErrorKind::UnexpectedEndOfBuffer => None,
+            _ => Some(self.len),
+        }
+    }
+}
+
+impl fmt::Display for Utf16ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Utf16ErrorKind::UnpairedSurrogate => "unpaired surrogate",
+            Utf16ErrorKind::UnexpectedEndOfBuffer => "incomplete code unit at end of input",
+        })
+    }
+}
+
+impl fmt::Display for Utf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid UTF-16 at byte offset {}: {}", self.offset, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Utf16Error {}
+
+#[derive(Clone)]
+pub struct Utf16Decoder<'a> {
+    status: Result<(), Utf16Error>,
+    mode: ErrorMode,
+    big_endian: bool,
+    raw: &'a [u8],
+    next: usize,
+}
+
+pub fn decode_utf16le(raw: &[u8]) -> Utf16Decoder<'_> {
+    Utf16Decoder { status: Ok(()), mode: ErrorMode::Report, big_endian: false, raw, next: 0 }
+}
+
+pub fn decode_utf16be(raw: &[u8]) -> Utf16Decoder<'_> {
+    Utf16Decoder { status: Ok(()), mode: ErrorMode::Report, big_endian: true, raw, next: 0 }
+}
+
+code_unit_decoder!(Utf16Decoder, Utf16DecoderPosition, Utf16Error, Utf16ErrorKind);
+
+impl<'a> Utf16Decoder<'a> {
+    fn unit(&self, at: usize) -> Option<u16> {
+        let bytes = self.raw.get(at..at + 2)?;
+        let bytes = [bytes[0], bytes[1]];
+        Some(if self.big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+    }
+    
+    fn decode(&mut self) -> Result<char, Utf16ErrorKind> {
+        let unit = match self.unit(self.next) {
+            Some(unit) => unit,
+            None => {
+                self.next = self.raw.len();
+                return Err(Utf16ErrorKind::UnexpectedEndOfBuffer);
+            },
+        };
+        self.next += 2;
+        
+        match unit {
+            0xD800..=0xDBFF => match self.unit(self.next) {
+                Some(low @ 0xDC00..=0xDFFF) => {
+                    self.next += 2;
+                    let high = (unit as u32 - 0xD800) << 10;
+                    let codepoint = 0x10000 + (high | (low as u32 - 0xDC00));
+                    Ok(unsafe { from_u32_unchecked(codepoint) })
+                },
+                Some(_) => Err(Utf16ErrorKind::UnpairedSurrogate),
+                None => {
+                    self.next = self.raw.len();
+                    Err(Utf16ErrorKind::UnexpectedEndOfBuffer)
+                },
+            },
+            0xDC00..=0xDFFF => Err(Utf16ErrorKind::UnpairedSurrogate),
+            _ => Ok(unsafe { from_u32_unchecked(unit as u32) }),
+        }
+    }
+}
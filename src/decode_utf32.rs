@@ -0,0 +1,97 @@
+use core::char::{from_u32, REPLACEMENT_CHARACTER};
+use core::fmt;
+
+use decode_utf8::{general_category, script, ErrorMode, GeneralCategory, Script, OTHER_SYMBOL};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Utf32ErrorKind {
+    SurrogateCharacter,
+    OutOfCharacterRange,
+    /// The input length is not a multiple of four.
+    UnexpectedEndOfBuffer,
+}
+
+/// An invalid code unit, located by its byte offset into the decoded input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utf32Error {
+    kind: Utf32ErrorKind,
+    offset: usize,
+    len: usize,
+}
+
+impl Utf32Error {
+    pub fn kind(&self) -> Utf32ErrorKind {
+        self.kind
+    }
+    
+    /// The byte offset of the invalid code unit.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    
+    /// The length of the invalid code unit in bytes, or `None` if the input
+    /// ended in the middle of one.
+    pub fn error_len(&self) -> Option<usize> {
+        match self.kind {
+            Utf32ErrorKind::UnexpectedEndOfBuffer => None,
+            _ => Some(self.len),
+        }
+    }
+}
+
+impl fmt::Display for Utf32ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Utf32ErrorKind::SurrogateCharacter => "surrogate code point",
+            Utf32ErrorKind::OutOfCharacterRange => "code point out of range",
+            Utf32ErrorKind::UnexpectedEndOfBuffer => "incomplete code unit at end of input",
+        })
+    }
+}
+
+impl fmt::Display for Utf32Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid UTF-32 at byte offset {}: {}", self.offset, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Utf32Error {}
+
+#[derive(Clone)]
+pub struct Utf32Decoder<'a> {
+    status: Result<(), Utf32Error>,
+    mode: ErrorMode,
+    big_endian: bool,
+    raw: &'a [u8],
+    next: usize,
+}
+
+pub fn decode_utf32le(raw: &[u8]) -> Utf32Decoder<'_> {
+    Utf32Decoder { status: Ok(()), mode: ErrorMode::Report, big_endian: false, raw, next: 0 }
+}
+
+pub fn decode_utf32be(raw: &[u8]) -> Utf32Decoder<'_> {
+    Utf32Decoder { status: Ok(()), mode: ErrorMode::Report, big_endian: true, raw, next: 0 }
+}
+
+code_unit_decoder!(Utf32Decoder, Utf32DecoderPosition, Utf32Error, Utf32ErrorKind);
+
+impl<'a> Utf32Decoder<'a> {
+    fn decode(&mut self) -> Result<char, Utf32ErrorKind> {
+        let bytes = match self.raw.get(self.next..self.next + 4) {
+            Some(bytes) => [bytes[0], bytes[1], bytes[2], bytes[3]],
+            None => {
+                self.next = self.raw.len();
+                return Err(Utf32ErrorKind::UnexpectedEndOfBuffer);
+            },
+        };
+        self.next += 4;
+        
+        let unit = if self.big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) };
+        match unit {
+            0xD800..=0xDFFF => Err(Utf32ErrorKind::SurrogateCharacter),
+            _ => from_u32(unit).ok_or(Utf32ErrorKind::OutOfCharacterRange),
+        }
+    }
+}
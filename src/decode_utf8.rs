@@ -1,5 +1,5 @@
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub enum Utf8Error {
+pub enum Utf8ErrorKind {
     NotALeadingByte,
     NotAContinuationByte,
     OverlongEncoding,
@@ -8,60 +8,363 @@ pub enum Utf8Error {
     UnexpectedEndOfBuffer,
 }
 
+/// An ill-formed sequence, located by its byte offset into the decoded input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utf8Error {
+    kind: Utf8ErrorKind,
+    offset: usize,
+    len: u8,
+    // Up to six, for a surrogate pair in WTF-8.
+    bytes: [u8; 6],
+}
+
+impl Utf8Error {
+    pub(crate) fn new(kind: Utf8ErrorKind, offset: usize, bytes: &[u8]) -> Utf8Error {
+        let mut buf = [0; 6];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Utf8Error { kind, offset, len: bytes.len() as u8, bytes: buf }
+    }
+    
+    pub fn kind(&self) -> Utf8ErrorKind {
+        self.kind
+    }
+    
+    /// The byte offset at which the ill-formed sequence starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    
+    /// The length of the ill-formed sequence, or `None` if the input ended in
+    /// the middle of an otherwise valid sequence (cf. `std::str::Utf8Error`).
+    pub fn error_len(&self) -> Option<usize> {
+        match self.kind {
+            UnexpectedEndOfBuffer => None,
+            _ => Some(self.len as usize),
+        }
+    }
+    
+    /// The bytes making up the ill-formed sequence.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+}
+
+impl fmt::Display for Utf8ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            NotALeadingByte => "unexpected continuation byte",
+            NotAContinuationByte => "missing continuation byte",
+            OverlongEncoding => "overlong encoding",
+            SurrogateCharacter => "encoded surrogate code point",
+            OutOfCharacterRange => "code point out of range",
+            UnexpectedEndOfBuffer => "incomplete sequence at end of input",
+        })
+    }
+}
+
+impl fmt::Display for Utf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid UTF-8 at byte offset {}: {} (", self.offset, self.kind)?;
+        for (i, b) in self.bytes().iter().enumerate() {
+            if i > 0 { f.write_str(" ")?; }
+            write!(f, "{:02X}", b)?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Utf8Error {}
+
+/// How the decoder proceeds after encountering an ill-formed sequence.
+/// 
+/// In every mode, the offending bytes are skipped according to the "maximal
+/// subpart" rule from the Unicode Standard (section 3.9, also used by WHATWG),
+/// and `status()` reports the most recent error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorMode {
+    /// Return `None` for each ill-formed sequence; the following call resumes
+    /// decoding with the next byte that may start a new sequence.
+    Report,
+    /// Return U+FFFD REPLACEMENT CHARACTER for each ill-formed sequence, with
+    /// category `So` and script `Common`.
+    Replace,
+    /// Like Python's "surrogateescape" error handler, return each byte of an
+    /// ill-formed sequence as a lone surrogate from U+DC80 to U+DCFF, with
+    /// category `Cs` and script `Unknown`. `encode_escaped` turns these back
+    /// into the original bytes.
+    /// 
+    /// Only `Utf8Decoder::next_code_point` and its variants can return them;
+    /// everything else behaves as in `Replace`.
+    Escape,
+}
+
+/// The encoding forms `Utf8Decoder` can decode besides standard UTF-8.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Utf8Variant {
+    Standard,
+    /// WTF-8, which allows surrogate code points that are not part of a pair.
+    /// `next_code_point` and its variants return them with category `Cs` and
+    /// script `Unknown`; all other methods report them as errors. A high
+    /// surrogate directly followed by a low one is always an error.
+    Wtf8,
+    /// CESU-8, which encodes supplementary characters as a pair of 3-byte
+    /// surrogate sequences rather than as a 4-byte sequence.
+    Cesu8,
+    /// Java's Modified UTF-8, which is CESU-8 with U+0000 encoded as `C0 80`.
+    ModifiedUtf8,
+}
+
 #[derive(Clone)]
-pub struct Utf8Decoder {
+pub struct Utf8Decoder<'a> {
     status: Result<(), Utf8Error>,
+    mode: ErrorMode,
+    variant: Utf8Variant,
+    nonstandard: bool,
+    escaped: * const u8,
+    start: * const u8,
     first: * const u8,
     next: * const u8,
-    end: * const u8
+    end: * const u8,
+    limit: * const u8,
+    peek: PeekCache,
+    lines: Option<LineTracker>,
+    _buf: PhantomData<&'a [u8]>,
 }
 
-pub fn decode_utf8(raw: &[u8]) -> Utf8Decoder {
-    unsafe {
-        let first = &raw[0] as * const u8;
-        Utf8Decoder {
-            first: first,
-            status: Ok(()),
-            next: first,
-            end: first.offset(raw.len() as isize),
-        }
+/// How many characters of lookahead `Utf8Decoder` keeps decoded.
+const PEEK_CACHE: usize = 4;
+
+/// The results of the next few calls to `next_char_and_category` from `start`,
+/// along with where each of them leaves the decoder. Only valid as long as the
+/// decoder is at `start` and its input still ends at `end`.
+#[derive(Clone, Copy)]
+struct PeekCache {
+    start: * const u8,
+    end: * const u8,
+    len: usize,
+    items: [(Option<(char, GeneralCategory)>, * const u8); PEEK_CACHE],
+}
+
+// The raw pointers only ever refer into the borrowed `&'a [u8]`, so the decoder
+// is exactly as thread-safe as that slice.
+unsafe impl<'a> Send for Utf8Decoder<'a> {}
+unsafe impl<'a> Sync for Utf8Decoder<'a> {}
+
+/// Checks that `raw` is well-formed UTF-8, returning the same error that
+/// decoding it would run into first.
+pub fn validate(raw: &[u8]) -> Result<(), Utf8Error> {
+    let mut decoder = decode_utf8(raw);
+    loop {
+        decoder.take_ascii();
+        if decoder.next_char().is_none() { return decoder.status; }
+    }
+}
+
+pub fn decode_utf8<'a>(raw: &'a [u8]) -> Utf8Decoder<'a> {
+    let first = raw.as_ptr();
+    let end = unsafe { first.add(raw.len()) };
+    Utf8Decoder {
+        status: Ok(()),
+        mode: ErrorMode::Report,
+        variant: Utf8Variant::Standard,
+        nonstandard: false,
+        escaped: first,
+        start: first,
+        first,
+        next: first,
+        end,
+        limit: end,
+        peek: PeekCache {
+            start: first,
+            end,
+            len: 0,
+            items: [(None, first); PEEK_CACHE],
+        },
+        lines: None,
+        _buf: PhantomData,
+    }
+}
+
+/// A position in the input, along with the error state of the decoder at
+/// that point, so that `Utf8Decoder::reset_to` can restore both.
+#[derive(Clone, Copy)]
+pub struct Utf8DecoderPosition<'a> {
+    start: * const u8,
+    limit: * const u8,
+    raw: * const u8,
+    first: * const u8,
+    variant: Utf8Variant,
+    nonstandard: bool,
+    escaped: * const u8,
+    status: Result<(), Utf8Error>,
+    lines: Option<LineTracker>,
+    _buf: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Utf8DecoderPosition<'a> {
+    /// The line and column at this position, if the decoder was tracking them.
+    pub fn line_column(&self) -> Option<LineColumn> {
+        self.lines.map(|lines| lines.position)
+    }
+}
+
+unsafe impl<'a> Send for Utf8DecoderPosition<'a> {}
+unsafe impl<'a> Sync for Utf8DecoderPosition<'a> {}
+
+/// The unit in which `Utf8Decoder::track_lines` counts columns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnUnit {
+    Chars,
+    Utf16Units,
+    Bytes,
+}
+
+/// A zero-based line and column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineColumn {
+    line: usize,
+    column: usize,
+}
+
+impl LineColumn {
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    
+    pub fn column(&self) -> usize {
+        self.column
     }
 }
 
 #[derive(Clone, Copy)]
-pub struct Utf8DecoderPosition{raw: * const u8}
+struct LineTracker {
+    unit: ColumnUnit,
+    position: LineColumn,
+    after_cr: bool,
+}
+
+impl LineTracker {
+    fn advance(&mut self, cp: u32, len: usize) {
+        let after_cr = self.after_cr;
+        self.after_cr = cp == 0x0D;
+        
+        match cp {
+            0x0A if after_cr => {},
+            // Zl and Zp contain nothing but U+2028 and U+2029.
+            0x0A | 0x0D | 0x85 | 0x2028 | 0x2029 => {
+                self.position.line += 1;
+                self.position.column = 0;
+            },
+            _ => self.position.column += match self.unit {
+                ColumnUnit::Chars => 1,
+                ColumnUnit::Utf16Units => if cp >= 0x10000 { 2 } else { 1 },
+                ColumnUnit::Bytes => len,
+            },
+        }
+    }
+}
 
 // 
 // Unicode Script Property
 // 
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-#[repr(u8)]
-pub enum Script {
-    Unknown, Adlam, Ahom, AnatolianHieroglyphs, Arabic, Armenian, Avestan,
-    Balinese, Bamum, BassaVah, Batak, Bengali, Bhaiksuki, Bopomofo,
-    Brahmi, Braille, Buginese, Buhid, CanadianAboriginal, Carian, CaucasianAlbanian,
-    Chakma, Cham, Cherokee, Common, Coptic, Cuneiform, Cypriot, Cyrillic,
-    Deseret, Devanagari, Dogra, Duployan, EgyptianHieroglyphs, Elbasan,
-    Ethiopic, Georgian, Glagolitic, Gothic, Grantha, Greek, Gujarati,
-    GunjalaGondi, Gurmukhi, Han, Hangul, HanifiRohingya, Hanunoo, Hatran,
-    Hebrew, Hiragana, ImperialAramaic, Inherited, InscriptionalPahlavi,
-    InscriptionalParthian, Javanese, Kaithi, Kannada, Katakana, KayahLi,
-    Kharoshthi, Khmer, Khojki, Khudawadi, Lao, Latin, Lepcha, Limbu,
-    LinearA, LinearB, Lisu, Lycian, Lydian, Mahajani, Makasar, Malayalam,
-    Mandaic, Manichaean, Marchen, MasaramGondi, Medefaidrin, MeeteiMayek,
-    MendeKikakui, MeroiticCursive, MeroiticHieroglyphs, Miao, Modi,
-    Mongolian, Mro, Multani, Myanmar, Nabataean, NewTaiLue, Newa,
-    Nko, Nushu, Ogham, OlChiki, OldHungarian, OldItalic, OldNorthArabian,
-    OldPermic, OldPersian, OldSogdian, OldSouthArabian, OldTurkic,
-    Oriya, Osage, Osmanya, PahawhHmong, Palmyrene, PauCinHau, PhagsPa,
-    Phoenician, PsalterPahlavi, Rejang, Runic, Samaritan, Saurashtra,
-    Sharada, Shavian, Siddham, SignWriting, Sinhala, Sogdian, SoraSompeng,
-    Soyombo, Sundanese, SylotiNagri, Syriac, Tagalog, Tagbanwa, TaiLe,
-    TaiTham, TaiViet, Takri, Tamil, Tangut, Telugu, Thaana, Thai,
-    Tibetan, Tifinagh, Tirhuta, Ugaritic, Vai, WarangCiti, Yi, ZanabazarSquare,
+// The `Script` enum itself is generated from the UCD, along with its names.
+impl Script {
+    /// The long name of the script, like `Old_Italic`.
+    pub fn name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].1
+    }
+    
+    /// The short, ISO 15924 name of the script, like `Ital`.
+    pub fn short_name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].0
+    }
+    
+    /// Looks up a script by its long or short name.
+    pub fn from_name(name: &str) -> Option<Script> {
+        SCRIPT_NAMES.iter()
+            .position(|&(short, long)| name == short || name == long)
+            .map(|s| unsafe { transmute::<u8, Script>(s as u8) })
+    }
+}
+
+/// A set of scripts, as given by the Script_Extensions property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScriptSet {
+    bits: [u64; 3],
+}
+
+impl ScriptSet {
+    pub const fn empty() -> ScriptSet {
+        ScriptSet { bits: [0; 3] }
+    }
+    
+    pub const fn single(script: Script) -> ScriptSet {
+        let s = script as usize;
+        let mut bits = [0; 3];
+        bits[s / 64] = 1 << (s % 64);
+        ScriptSet { bits }
+    }
+    
+    pub const fn contains(&self, script: Script) -> bool {
+        let s = script as usize;
+        self.bits[s / 64] & (1 << (s % 64)) != 0
+    }
+    
+    pub const fn is_empty(&self) -> bool {
+        self.bits[0] | self.bits[1] | self.bits[2] == 0
+    }
+    
+    pub const fn len(&self) -> usize {
+        (self.bits[0].count_ones() + self.bits[1].count_ones() + self.bits[2].count_ones()) as usize
+    }
+    
+    pub const fn union(self, other: ScriptSet) -> ScriptSet {
+        let (a, b) = (self.bits, other.bits);
+        ScriptSet { bits: [a[0] | b[0], a[1] | b[1], a[2] | b[2]] }
+    }
+    
+    /// Narrows a set down to the scripts it shares with `other`. Text is
+    /// single-script if folding this over its characters, starting from the
+    /// set of all scripts, leaves anything other than the empty set.
+    pub const fn intersection(self, other: ScriptSet) -> ScriptSet {
+        let (a, b) = (self.bits, other.bits);
+        ScriptSet { bits: [a[0] & b[0], a[1] & b[1], a[2] & b[2]] }
+    }
+    
+    pub fn iter(&self) -> ScriptSetIter {
+        ScriptSetIter { bits: self.bits, word: 0 }
+    }
+}
+
+/// Iterates over the scripts in a `ScriptSet`, in the order of the `Script` enum.
+#[derive(Clone)]
+pub struct ScriptSetIter {
+    bits: [u64; 3],
+    word: usize,
+}
+
+impl Iterator for ScriptSetIter {
+    type Item = Script;
+    
+    fn next(&mut self) -> Option<Script> {
+        while self.word < 3 {
+            let bits = self.bits[self.word];
+            if bits != 0 {
+                self.bits[self.word] = bits & (bits - 1);
+                let s = self.word * 64 + bits.trailing_zeros() as usize;
+                return Some(unsafe { transmute::<u8, Script>(s as u8) });
+            }
+            self.word += 1;
+        }
+        
+        None
+    }
 }
 
+impl FusedIterator for ScriptSetIter {}
+
 // 
 // Unicode Property General_Category
 // 
@@ -78,88 +381,31 @@ impl BitSet for GeneralCategory {
     }
 }
 
-const LU: u8 = 0x00;
-const LL: u8 = 0x01;
-const LT: u8 = 0x02;
-const LM: u8 = 0x03;
-const LO: u8 = 0x04;
-const MN: u8 = 0x05;
-const MC: u8 = 0x06;
-const ME: u8 = 0x07;
-const ND: u8 = 0x08;
-const NL: u8 = 0x09;
-const NO: u8 = 0x0A;
-const PC: u8 = 0x0B;
-const PD: u8 = 0x0C;
-const PS: u8 = 0x0D;
-const PE: u8 = 0x0E;
-const PI: u8 = 0x0F;
-const PF: u8 = 0x10;
-const PO: u8 = 0x11;
-const SM: u8 = 0x12;
-const SC: u8 = 0x13;
-const SK: u8 = 0x14;
-const SO: u8 = 0x15;
-const ZS: u8 = 0x16;
-const ZL: u8 = 0x17;
-const ZP: u8 = 0x18;
-const CC: u8 = 0x19;
-const CF: u8 = 0x1A;
-const CS: u8 = 0x1B;
-const CO: u8 = 0x1C;
-const CN: u8 = 0x1D;
-
-pub const UPPERCASE_LETTER: GeneralCategory = 1 << LU;
-pub const LOWERCASE_LETTER: GeneralCategory = 1 << LL;
-pub const TITLECASE_LETTER: GeneralCategory = 1 << LT;
-pub const MODIFIER_LETTER: GeneralCategory = 1 << LM;
-pub const OTHER_LETTER: GeneralCategory = 1 << LO;
-pub const CASED_LETTER: GeneralCategory =
-    UPPERCASE_LETTER | LOWERCASE_LETTER | TITLECASE_LETTER;
-pub const LETTER: GeneralCategory =
-    CASED_LETTER | MODIFIER_LETTER | OTHER_LETTER;
-
-pub const NONSPACING_MARK: GeneralCategory = 1 << MN;
-pub const SPACING_MARK: GeneralCategory = 1 << MC;
-pub const ENCLOSING_MARK: GeneralCategory = 1 << ME;
-pub const MARK: GeneralCategory = NONSPACING_MARK | SPACING_MARK | ENCLOSING_MARK;
-
-pub const DECIMAL_NUMBER: GeneralCategory = 1 << ND;
-pub const LETTER_NUMBER: GeneralCategory = 1 << NL;
-pub const OTHER_NUMBER: GeneralCategory = 1 << NO;
-pub const NUMBER: GeneralCategory = DECIMAL_NUMBER | LETTER_NUMBER | OTHER_NUMBER;
-
-pub const CONNECTOR_PUNCTUATION: GeneralCategory = 1 << PC;
-pub const DASH_PUNCTUATION: GeneralCategory = 1 << PD;
-pub const OPEN_PUNCTUATION: GeneralCategory = 1 << PS;
-pub const CLOSE_PUNCTUATION: GeneralCategory = 1 << PE;
-pub const INITIAL_PUNCTUATION: GeneralCategory = 1 << PI;
-pub const FINAL_PUNCTUATION: GeneralCategory = 1 << PF;
-pub const OTHER_PUNCTUATION: GeneralCategory = 1 << PO;
-pub const PUNCTUATION: GeneralCategory =
-    CONNECTOR_PUNCTUATION | DASH_PUNCTUATION | OPEN_PUNCTUATION | CLOSE_PUNCTUATION |
-    INITIAL_PUNCTUATION   | FINAL_PUNCTUATION | OTHER_PUNCTUATION;
-
-pub const MATH_SYMBOL: GeneralCategory = 1 << SM;
-pub const CURRENCY_SYMBOL: GeneralCategory = 1 << SC;
-pub const MODIFIER_SYMBOL: GeneralCategory = 1 << SK;
-pub const OTHER_SYMBOL: GeneralCategory = 1 << SO;
-pub const SYMBOL: GeneralCategory =
-    MATH_SYMBOL | CURRENCY_SYMBOL | MODIFIER_SYMBOL | OTHER_SYMBOL;
-
-pub const SPACE_SEPERATOR: GeneralCategory = 1 << ZS;
-pub const LINE_SEPERATOR: GeneralCategory = 1 << ZL;
-pub const PARAGRAPH_SEPERATOR: GeneralCategory = 1 << ZP;
-pub const SEPERATOR: GeneralCategory =
-    SPACE_SEPERATOR | LINE_SEPERATOR | PARAGRAPH_SEPERATOR;
-
-pub const CONTROL: GeneralCategory = 1 << CC;
-pub const FORMAT: GeneralCategory = 1 << CF;
-pub const SURROGATE: GeneralCategory = 1 << CS;
-pub const PRIVATE_USE: GeneralCategory = 1 << CO;
-pub const UNASSIGNED: GeneralCategory = 1 << CN;
-pub const OTHER: GeneralCategory =
-    CONTROL | FORMAT | SURROGATE | PRIVATE_USE | UNASSIGNED;
+// The constants for each value are generated from the UCD, so that they
+// match the tables.
+
+/// Looks up a general category, or a group of them like `L`, by its long or
+/// short name.
+pub fn general_category_by_name(name: &str) -> Option<GeneralCategory> {
+    CATEGORY_NAMES.iter()
+        .find(|&&(short, long, _)| name == short || name == long)
+        .map(|&(_, _, cat)| cat)
+}
+
+/// The short name of a general category or group, like `Lu` or `L`, if `cat`
+/// is exactly one of them.
+pub fn general_category_name(cat: GeneralCategory) -> Option<&'static str> {
+    CATEGORY_NAMES.iter().find(|&&(_, _, c)| c == cat).map(|&(short, _, _)| short)
+}
+
+#[deprecated(note = "use SPACE_SEPARATOR")]
+pub const SPACE_SEPERATOR: GeneralCategory = SPACE_SEPARATOR;
+#[deprecated(note = "use LINE_SEPARATOR")]
+pub const LINE_SEPERATOR: GeneralCategory = LINE_SEPARATOR;
+#[deprecated(note = "use PARAGRAPH_SEPARATOR")]
+pub const PARAGRAPH_SEPERATOR: GeneralCategory = PARAGRAPH_SEPARATOR;
+#[deprecated(note = "use SEPARATOR")]
+pub const SEPERATOR: GeneralCategory = SEPARATOR;
 
 // 
 // Input Byte Classification
@@ -201,7 +447,9 @@ const CHAR_CLASSES: [u8; 256] = [
 // State Transitions
 // 
 
+#[allow(clippy::erasing_op)]
 const OK: u8 =  0 * 12; // initial state, accept
+#[allow(clippy::identity_op)]
 const G1: u8 =  1 * 12; // get 1 more byte
 const G2: u8 =  2 * 12; // get 2 more bytes
 const O2: u8 =  3 * 12; // get 2 more bytes, check overlong
@@ -232,29 +480,161 @@ const NEXT_STATE: [u8; 156] = [
     /* ER */ ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER,
 ];
 
-use self::Utf8Error::*;
-use std::char::from_u32_unchecked;
-use std::mem::transmute;
-use std::slice::from_raw_parts;
-use std::str::from_utf8_unchecked;
-use tables::*;
+// 
+// Variant Tables
+// 
+
+// CESU-8 and Modified UTF-8 have no 4-byte sequences, which frees up the L4R
+// and L4N classes for Modified UTF-8 to tell `C0 80` apart from other overlong
+// 2-byte sequences.
+const L2Z: u8 = L4R; // leading byte C0, only valid as C0 80
+const C80: u8 = L4N; // continuation byte 80
+
+const CESU8_CHAR_CLASSES: [u8; 256] = [
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00,
+    C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01,
+    C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X,
+    C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X,
+    ERR, ERR, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N,
+    L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N,
+    L3O, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3S, L3N, L3N,
+    ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR,
+];
+
+const MUTF8_CHAR_CLASSES: [u8; 256] = [
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC, ASC,
+    C80, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00, C00,
+    C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01, C01,
+    C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X,
+    C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X, C1X,
+    L2Z, ERR, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N,
+    L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N, L2N,
+    L3O, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3N, L3S, L3N, L3N,
+    ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR,
+];
+
+// Shared by WTF-8 and CESU-8: the same as NEXT_STATE, but S2 lets surrogates
+// through like G2 does.
+const WTF8_NEXT_STATE: [u8; 156] = [
+    /*       ASC C00 L2N L3N L3S L4R L4N C1X ERR C01 L3O L4O */
+    /* OK */ OK, EL, G1, G2, S2, R3, G3, EL, EL, EL, O2, O3,
+    /* G1 */ EC, OK, EC, EC, EC, EC, EC, OK, EC, OK, EC, EC,
+    /* G2 */ EC, G1, EC, EC, EC, EC, EC, G1, EC, G1, EC, EC,
+    /* O2 */ EC, EO, EC, EC, EC, EC, EC, G1, EC, EO, EC, EC,
+    /* S2 */ EC, G1, EC, EC, EC, EC, EC, G1, EC, G1, EC, EC,
+    /* G3 */ EC, G2, EC, EC, EC, EC, EC, G2, EC, G2, EC, EC,
+    /* O3 */ EC, EO, EC, EC, EC, EC, EC, G2, EC, G2, EC, EC,
+    /* R3 */ EC, G2, EC, EC, EC, EC, EC, ER, EC, ER, EC, EC,
+    /* EL */ EL, EL, EL, EL, EL, EL, EL, EL, EL, EL, EL, EL,
+    /* EC */ EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC,
+    /* EO */ EO, EO, EO, EO, EO, EO, EO, EO, EO, EO, EO, EO,
+    /* ES */ ES, ES, ES, ES, ES, ES, ES, ES, ES, ES, ES, ES,
+    /* ER */ ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER,
+];
+
+// Without 4-byte sequences, G3 and O3 are unreachable, and R3 is reused.
+const N1: u8 = R3; // get 80 after C0
+
+const MUTF8_NEXT_STATE: [u8; 156] = [
+    /*       ASC C00 L2N L3N L3S L2Z C80 C1X ERR C01 L3O  -- */
+    /* OK */ OK, EL, G1, G2, S2, N1, EL, EL, EL, EL, O2, EL,
+    /* G1 */ EC, OK, EC, EC, EC, EC, OK, OK, EC, OK, EC, EC,
+    /* G2 */ EC, G1, EC, EC, EC, EC, G1, G1, EC, G1, EC, EC,
+    /* O2 */ EC, EO, EC, EC, EC, EC, EO, G1, EC, EO, EC, EC,
+    /* S2 */ EC, G1, EC, EC, EC, EC, G1, G1, EC, G1, EC, EC,
+    /* G3 */ EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC,
+    /* O3 */ EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC,
+    /* N1 */ EC, EO, EC, EC, EC, EC, OK, EO, EC, EO, EC, EC,
+    /* EL */ EL, EL, EL, EL, EL, EL, EL, EL, EL, EL, EL, EL,
+    /* EC */ EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC, EC,
+    /* EO */ EO, EO, EO, EO, EO, EO, EO, EO, EO, EO, EO, EO,
+    /* ES */ ES, ES, ES, ES, ES, ES, ES, ES, ES, ES, ES, ES,
+    /* ER */ ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER, ER,
+];
+
+struct Dfa {
+    classes: &'static [u8; 256],
+    states: &'static [u8; 156],
+    /// Whether supplementary characters are encoded as surrogate pairs.
+    pairs: bool,
+}
+
+impl Utf8Variant {
+    fn dfa(self) -> Dfa {
+        match self {
+            Utf8Variant::Standard =>
+                Dfa { classes: &CHAR_CLASSES, states: &NEXT_STATE, pairs: false },
+            Utf8Variant::Wtf8 =>
+                Dfa { classes: &CHAR_CLASSES, states: &WTF8_NEXT_STATE, pairs: false },
+            Utf8Variant::Cesu8 =>
+                Dfa { classes: &CESU8_CHAR_CLASSES, states: &WTF8_NEXT_STATE, pairs: true },
+            Utf8Variant::ModifiedUtf8 =>
+                Dfa { classes: &MUTF8_CHAR_CLASSES, states: &MUTF8_NEXT_STATE, pairs: true },
+        }
+    }
+}
+
+use self::Utf8ErrorKind::*;
+use core::char::{from_u32, from_u32_unchecked, REPLACEMENT_CHARACTER};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::transmute;
+use core::slice::from_raw_parts;
+use core::str::from_utf8_unchecked;
+use ascii::ascii_prefix_len;
+pub use tables::*;
+
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::vec::Vec;
 
 macro_rules! d_lead {
     ($lead:expr) => {
         {
             let class = CHAR_CLASSES[$lead as usize];
-            let mut codepoint = (0xFFu32 >> class) & ($lead as u32);
-            let mut state = NEXT_STATE[class as usize];
+            let codepoint = (0xFFu32 >> class) & ($lead as u32);
+            let state = NEXT_STATE[class as usize];
             (codepoint, state)
         }
     }
 }
 
+/// Checks for an error state, or for the end of the input. If `prev_char`
+/// has moved `end` back, the byte that actually follows still decides what
+/// kind of error the sequence is, just like it does when decoding backward;
+/// it is consumed here so that `set_err!` backs off over it again.
 macro_rules! chk_err {
     ($this:expr, $state:expr) => {
         {
             if * $state >= EL { true }
-            else if $this.next >= $this.end { * $state = 0; true }
+            else if $this.next >= $this.end {
+                let follow = if $this.next < $this.limit {
+                    NEXT_STATE[(* $state + CHAR_CLASSES[* $this.next as usize]) as usize]
+                } else { 0 };
+                
+                if follow >= EL { $this.next = $this.next.offset(1); * $state = follow; }
+                else { * $state = 0; }
+                true
+            }
             else { false }
         }
     }
@@ -280,38 +660,213 @@ macro_rules! d_cont {
     }
 }
 
+fn error_kind(state: u8) -> Utf8ErrorKind {
+    match state {
+        EL => NotALeadingByte,
+        EC => NotAContinuationByte,
+        EO => OverlongEncoding,
+        ES => SurrogateCharacter,
+        ER => OutOfCharacterRange,
+        0 => UnexpectedEndOfBuffer,
+        _ => unreachable!()
+    }
+}
+
 macro_rules! set_err {
-    ($this:expr, $state:expr) => {
+    ($this:expr, $seq:expr, $state:expr, $replacement:expr) => {
         {
-            $this.first = $this.next;
-            $this.status = Err(match $state {
-                               EL => NotALeadingByte,
-                               EC => NotAContinuationByte,
-                               EO => OverlongEncoding,
-                               ES => SurrogateCharacter,
-                               ER => OutOfCharacterRange,
-                               0 => UnexpectedEndOfBuffer,
-                               _ => unreachable!()
-                               });
-            None
+            // An unexpected byte is not part of the ill-formed sequence; leave
+            // it to start the next one.
+            if $state >= EC { $this.next = $this.next.offset(-1); }
+            $this.fail($seq, $state, $replacement)
         }
     }
 }
 
 macro_rules! i { ($a:ident[$n:expr, $b:expr]) => ($a[(($n as usize) << 6) + ($b as usize)]) }
 
-impl Utf8Decoder {
+/// Evaluates `$e`, then updates line tracking for what it consumed.
+macro_rules! track_lines {
+    ($this:expr, $e:expr) => {
+        {
+            let seq = $this.next;
+            let result = $e;
+            if $this.lines.is_some() { $this.advance_lines(seq); }
+            result
+        }
+    }
+}
+
+/// Consumes characters while the property value `$v` looked up in the given
+/// trie satisfies `$pred`. Stops before ill-formed sequences without reporting
+/// them.
+macro_rules! scan_while {
+    ($this:expr, $values:ident, $index:ident, |$v:ident| $pred:expr) => {
+        unsafe {
+            while $this.next < $this.end {
+                let byte = * $this.next;
+                if byte < 0x80 {
+                    let $v = $values[byte as usize];
+                    if !$pred { break; }
+                    $this.next = $this.next.offset(1);
+                    continue;
+                }
+                
+                let (_, mut state) = d_lead!(byte);
+                let mut idx = $index[byte as usize];
+                let mut p = $this.next.offset(1);
+                let value = loop {
+                    if state >= EL || p >= $this.end { break None; }
+                    let byte = *p;
+                    state = NEXT_STATE[(state + CHAR_CLASSES[byte as usize]) as usize];
+                    if state >= EL { break None; }
+                    
+                    p = p.offset(1);
+                    if state == OK { break Some(i!($values[idx, byte])); }
+                    idx = i!($index[idx, byte]);
+                };
+                
+                match value {
+                    Some($v) if $pred => $this.next = p,
+                    _ => break,
+                }
+            }
+        }
+    }
+}
+
+/// Decodes the sequence starting at `p` like `next_char` does, but with the
+/// tables of `dfa`, and without reading at or past `limit`. Returns the code
+/// point or the error state, and the end of the sequence or maximal subpart.
+unsafe fn decode_at(p: * const u8, limit: * const u8, dfa: &Dfa) -> (Result<u32, u8>, * const u8) {
+    let byte = *p;
+    let mut p = p.offset(1);
+    if byte < 0x80 { return (Ok(byte as u32), p); }
+    
+    let class = dfa.classes[byte as usize];
+    let mut codepoint = (0xFFu32 >> class) & (byte as u32);
+    let mut state = dfa.states[class as usize];
+    while state < EL {
+        if p >= limit { return (Err(0), p); }
+        let byte = *p;
+        let class = dfa.classes[byte as usize];
+        state = dfa.states[(state + class) as usize];
+        if state >= EL { break; }
+        
+        codepoint = (byte as u32 & 0x3F) | (codepoint << 6);
+        p = p.offset(1);
+        if state == OK { return (Ok(codepoint), p); }
+    }
+    
+    (Err(state), p)
+}
+
+fn combine_surrogates(high: u32, low: u32) -> u32 {
+    0x10000 + (((high - 0xD800) << 10) | (low - 0xDC00))
+}
+
+/// Splits a value from the combined `PROPS` trie into category and script.
+unsafe fn unpack_props(props: u16) -> (GeneralCategory, Script) {
+    let cat = (1 << (props & 0x1F)) as GeneralCategory;
+    (cat, transmute::<u8, Script>((props >> 5) as u8))
+}
+
+/// Looks up the general category of `c`, without decoding anything.
+pub const fn general_category(c: char) -> GeneralCategory {
+    (1 << lookup(CAT_VALUES, CAT_INDEX, c)) as GeneralCategory
+}
+
+/// Looks up the script of `c`, without decoding anything.
+pub const fn script(c: char) -> Script {
+    unsafe { transmute::<u8, Script>(lookup(SCRIPT_VALUES, SCRIPT_INDEX, c)) }
+}
+
+/// Looks up the scripts `c` is used with, without decoding anything. For most
+/// characters, this is just the set containing `script(c)`.
+pub const fn script_extensions(c: char) -> ScriptSet {
+    match lookup(SCX_VALUES, SCX_INDEX, c) {
+        0 => ScriptSet::single(script(c)),
+        id => ScriptSet { bits: SCX_SETS[id as usize - 1] },
+    }
+}
+
+/// Combines values from the `SCRIPT` and `SCX` tries into a character's set.
+const fn script_set(scr: u8, scx: u8) -> ScriptSet {
+    match scx {
+        0 => ScriptSet::single(unsafe { transmute::<u8, Script>(scr) }),
+        id => ScriptSet { bits: SCX_SETS[id as usize - 1] },
+    }
+}
+
+fn props_of(c: char) -> (GeneralCategory, Script) {
+    unsafe { unpack_props(lookup(PROPS_VALUES, PROPS_INDEX, c)) }
+}
+
+// Surrogates are not in the tries, since they are not characters.
+fn code_point_category(cp: u32) -> GeneralCategory {
+    from_u32(cp).map_or(SURROGATE, general_category)
+}
+
+fn code_point_script(cp: u32) -> Script {
+    from_u32(cp).map_or(Script::Unknown, script)
+}
+
+/// Walks one of the property tries along the UTF-8 encoding of a character,
+/// computing each byte as it goes.
+const fn lookup<T: Copy>(values: &[T], index: &[u16], c: char) -> T {
+    let cp = c as u32;
+    if cp < 0x80 { return values[cp as usize]; }
+    
+    let (lead, mut shift) = match cp {
+        0x80..=0x7FF => (0xC0 | (cp >> 6), 0),
+        0x800..=0xFFFF => (0xE0 | (cp >> 12), 6),
+        _ => (0xF0 | (cp >> 18), 12),
+    };
+    
+    let mut idx = index[lead as usize];
+    while shift > 0 {
+        idx = i!(index[idx, 0x80 | ((cp >> shift) & 0x3F)]);
+        shift -= 6;
+    }
+    i!(values[idx, 0x80 | (cp & 0x3F)])
+}
+
+impl<'a> Utf8Decoder<'a> {
     pub fn status(&self) -> Result<(), Utf8Error> {
         self.status
     }
     
+    pub fn set_error_mode(&mut self, mode: ErrorMode) {
+        self.mode = mode;
+        self.peek.len = 0;
+    }
+    
+    pub fn set_variant(&mut self, variant: Utf8Variant) {
+        self.variant = variant;
+        self.nonstandard |= variant != Utf8Variant::Standard;
+        self.peek.len = 0;
+    }
+    
+    /// Decodes the next character. This handles one byte at a time even in
+    /// long ASCII runs; call `take_ascii` first to skip those many bytes at a
+    /// time where the input is mostly ASCII.
     pub fn next_char(&mut self) -> Option<char> {
+        track_lines!(self, self.decode_char())
+    }
+    
+    #[inline(always)]
+    fn decode_char(&mut self) -> Option<char> {
         if self.next >= self.end { return None; }
         
         unsafe {
+            let seq = self.next;
             let byte = next_b!(self);
             if byte < 0x80 { return Some(byte as char); }
             
+            if self.variant != Utf8Variant::Standard {
+                return self.next_variant(seq, false, |cp| from_u32_unchecked(cp), REPLACEMENT_CHARACTER);
+            }
+            
             let (mut codepoint, mut state) = d_lead!(byte);
             for _ in 1..4 {
                 if chk_err!(self, &mut state) { break; }
@@ -321,14 +876,20 @@ impl Utf8Decoder {
                 if state == OK { return Some(from_u32_unchecked(codepoint)); }
             }
             
-            return set_err!(self, state);
+            set_err!(self, seq, state, REPLACEMENT_CHARACTER)
         }
     }
     
     pub fn next_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+        track_lines!(self, self.decode_char_and_category())
+    }
+    
+    #[inline(always)]
+    fn decode_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
         if self.next >= self.end { return None; }
         
         unsafe {
+            let seq = self.next;
             let byte = next_b!(self);
             
             if byte < 0x80 {
@@ -336,6 +897,14 @@ impl Utf8Decoder {
                 return Some((byte as char, (1 << cat) as GeneralCategory));
             }
             
+            if self.variant != Utf8Variant::Standard {
+                let replacement = (REPLACEMENT_CHARACTER, OTHER_SYMBOL);
+                return self.next_variant(seq, false, |cp| {
+                    let c = from_u32_unchecked(cp);
+                    (c, general_category(c))
+                }, replacement);
+            }
+            
             let (mut codepoint, mut state) = d_lead!(byte);
             let  mut cat_idx = CAT_INDEX[byte as usize];
             
@@ -348,25 +917,40 @@ impl Utf8Decoder {
                     let cat = (1 << i!(CAT_VALUES[cat_idx, byte])) as GeneralCategory;
                     return Some((from_u32_unchecked(codepoint), cat));
                 }
+                if state >= EL { break; }
                 
                 cat_idx = i!(CAT_INDEX[cat_idx, byte]);
             }
             
-            return set_err!(self, state);
+            set_err!(self, seq, state, (REPLACEMENT_CHARACTER, OTHER_SYMBOL))
         }
     }
     
     pub fn next_char_and_script(&mut self) -> Option<(char, Script)> {
+        track_lines!(self, self.decode_char_and_script())
+    }
+    
+    #[inline(always)]
+    fn decode_char_and_script(&mut self) -> Option<(char, Script)> {
         if self.next >= self.end { return None; }
         
         unsafe {
+            let seq = self.next;
             let byte = next_b!(self);
             
             if byte < 0x80 {
-                let scr = transmute(SCRIPT_VALUES[byte as usize]);
+                let scr = transmute::<u8, Script>(SCRIPT_VALUES[byte as usize]);
                 return Some((byte as char, scr));
             }
             
+            if self.variant != Utf8Variant::Standard {
+                let replacement = (REPLACEMENT_CHARACTER, Script::Common);
+                return self.next_variant(seq, false, |cp| {
+                    let c = from_u32_unchecked(cp);
+                    (c, script(c))
+                }, replacement);
+            }
+            
             let (mut codepoint, mut state) = d_lead!(byte);
             let  mut scr_idx = SCRIPT_INDEX[byte as usize];
             
@@ -376,36 +960,1102 @@ impl Utf8Decoder {
                 d_cont!(byte, &mut codepoint, &mut state);
                 
                 if state == OK {
-                    let scr = transmute(i!(SCRIPT_VALUES[scr_idx, byte]));
+                    let scr = transmute::<u8, Script>(i!(SCRIPT_VALUES[scr_idx, byte]));
                     return Some((from_u32_unchecked(codepoint), scr));
                 }
+                if state >= EL { break; }
                 
                 scr_idx = i!(SCRIPT_INDEX[scr_idx, byte]);
             }
             
-            return set_err!(self, state);
+            set_err!(self, seq, state, (REPLACEMENT_CHARACTER, Script::Common))
         }
     }
     
-    pub fn mark(&self) -> Utf8DecoderPosition {
-        Utf8DecoderPosition{raw: self.next}
+    /// Decodes the next character along with every script it is used with.
+    /// Replacement characters come with the set containing only `Common`.
+    pub fn next_char_and_script_extensions(&mut self) -> Option<(char, ScriptSet)> {
+        track_lines!(self, self.decode_char_and_script_extensions())
     }
     
-    pub fn try_get_marked_string(&self, mark: Utf8DecoderPosition)
-      -> Result<&str, Utf8Error>
-    {
-        match self.status {
-            Ok(_) => {
-                assert!(self.first <= mark.raw && mark.raw <= self.next);
-            },
-            Err(e) => if mark.raw < self.first {
-                return Err(e);
-            } else {
-                assert!(mark.raw <= self.next);
-            }
-        }
+    // The `SCX` trie only holds the sets of characters with more than one
+    // script, so the `SCRIPT` trie is walked alongside it for the others.
+    #[inline(always)]
+    fn decode_char_and_script_extensions(&mut self) -> Option<(char, ScriptSet)> {
+        if self.next >= self.end { return None; }
         
-        let size = (self.next as usize) - (mark.raw as usize);
-        unsafe { Ok(from_utf8_unchecked(from_raw_parts(mark.raw, size))) }
-    }
-}
\ No newline at end of file
+        unsafe {
+            let seq = self.next;
+            let byte = next_b!(self);
+            
+            if byte < 0x80 {
+                let set = script_set(SCRIPT_VALUES[byte as usize], SCX_VALUES[byte as usize]);
+                return Some((byte as char, set));
+            }
+            
+            if self.variant != Utf8Variant::Standard {
+                let replacement = (REPLACEMENT_CHARACTER, ScriptSet::single(Script::Common));
+                return self.next_variant(seq, false, |cp| {
+                    let c = from_u32_unchecked(cp);
+                    (c, script_extensions(c))
+                }, replacement);
+            }
+            
+            let (mut codepoint, mut state) = d_lead!(byte);
+            let  mut scr_idx = SCRIPT_INDEX[byte as usize];
+            let  mut scx_idx = SCX_INDEX[byte as usize];
+            
+            for _ in 1..4 {
+                if chk_err!(self, &mut state) { break; }
+                let byte = next_b!(self);
+                d_cont!(byte, &mut codepoint, &mut state);
+                
+                if state == OK {
+                    let set = script_set(i!(SCRIPT_VALUES[scr_idx, byte]), i!(SCX_VALUES[scx_idx, byte]));
+                    return Some((from_u32_unchecked(codepoint), set));
+                }
+                if state >= EL { break; }
+                
+                scr_idx = i!(SCRIPT_INDEX[scr_idx, byte]);
+                scx_idx = i!(SCX_INDEX[scx_idx, byte]);
+            }
+            
+            set_err!(self, seq, state, (REPLACEMENT_CHARACTER, ScriptSet::single(Script::Common)))
+        }
+    }
+    
+    /// Decodes the next character along with both its general category and
+    /// its script, looking both up in the same trie walk.
+    pub fn next_char_and_properties(&mut self) -> Option<(char, GeneralCategory, Script)> {
+        track_lines!(self, self.decode_char_and_properties())
+    }
+    
+    #[inline(always)]
+    fn decode_char_and_properties(&mut self) -> Option<(char, GeneralCategory, Script)> {
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let seq = self.next;
+            let byte = next_b!(self);
+            
+            if byte < 0x80 {
+                let (cat, scr) = unpack_props(PROPS_VALUES[byte as usize]);
+                return Some((byte as char, cat, scr));
+            }
+            
+            if self.variant != Utf8Variant::Standard {
+                let replacement = (REPLACEMENT_CHARACTER, OTHER_SYMBOL, Script::Common);
+                return self.next_variant(seq, false, |cp| {
+                    let c = from_u32_unchecked(cp);
+                    let (cat, scr) = props_of(c);
+                    (c, cat, scr)
+                }, replacement);
+            }
+            
+            let (mut codepoint, mut state) = d_lead!(byte);
+            let  mut props_idx = PROPS_INDEX[byte as usize];
+            
+            for _ in 1..4 {
+                if chk_err!(self, &mut state) { break; }
+                let byte = next_b!(self);
+                d_cont!(byte, &mut codepoint, &mut state);
+                
+                if state == OK {
+                    let (cat, scr) = unpack_props(i!(PROPS_VALUES[props_idx, byte]));
+                    return Some((from_u32_unchecked(codepoint), cat, scr));
+                }
+                if state >= EL { break; }
+                
+                props_idx = i!(PROPS_INDEX[props_idx, byte]);
+            }
+            
+            set_err!(self, seq, state, (REPLACEMENT_CHARACTER, OTHER_SYMBOL, Script::Common))
+        }
+    }
+    
+    /// Like `next_char`, but returns the code point as a `u32`, so that lone
+    /// surrogates can be returned in `Utf8Variant::Wtf8`.
+    pub fn next_code_point(&mut self) -> Option<u32> {
+        track_lines!(self, self.decode_code_point())
+    }
+    
+    fn decode_code_point(&mut self) -> Option<u32> {
+        if self.mode == ErrorMode::Escape { return self.next_escaped(|cp| cp); }
+        if self.variant != Utf8Variant::Wtf8 { return self.decode_char().map(|c| c as u32); }
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let seq = self.next;
+            self.next_variant(seq, true, |cp| cp, REPLACEMENT_CHARACTER as u32)
+        }
+    }
+    
+    pub fn next_code_point_and_category(&mut self) -> Option<(u32, GeneralCategory)> {
+        track_lines!(self, self.decode_code_point_and_category())
+    }
+    
+    fn decode_code_point_and_category(&mut self) -> Option<(u32, GeneralCategory)> {
+        if self.mode == ErrorMode::Escape {
+            return self.next_escaped(|cp| (cp, code_point_category(cp)));
+        }
+        if self.variant != Utf8Variant::Wtf8 {
+            return self.decode_char_and_category().map(|(c, cat)| (c as u32, cat));
+        }
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let seq = self.next;
+            let replacement = (REPLACEMENT_CHARACTER as u32, OTHER_SYMBOL);
+            self.next_variant(seq, true, |cp| (cp, code_point_category(cp)), replacement)
+        }
+    }
+    
+    pub fn next_code_point_and_script(&mut self) -> Option<(u32, Script)> {
+        track_lines!(self, self.decode_code_point_and_script())
+    }
+    
+    fn decode_code_point_and_script(&mut self) -> Option<(u32, Script)> {
+        if self.mode == ErrorMode::Escape {
+            return self.next_escaped(|cp| (cp, code_point_script(cp)));
+        }
+        if self.variant != Utf8Variant::Wtf8 {
+            return self.decode_char_and_script().map(|(c, scr)| (c as u32, scr));
+        }
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let seq = self.next;
+            let replacement = (REPLACEMENT_CHARACTER as u32, Script::Common);
+            self.next_variant(seq, true, |cp| (cp, code_point_script(cp)), replacement)
+        }
+    }
+    
+    // 
+    // The variants go through `decode_at` instead of the unrolled loops above,
+    // and combine surrogate pairs where the variant encodes them. Surrogates
+    // left over are errors, unless `surrogates` is set in WTF-8 mode. WTF-8
+    // encodes supplementary characters as 4-byte sequences only, so a high
+    // surrogate directly followed by a low one is always an error there, and
+    // reported as a single ill-formed sequence.
+    // 
+    
+    #[cold]
+    unsafe fn next_variant<T, F>(&mut self, seq: * const u8, surrogates: bool, f: F, replacement: T)
+      -> Option<T> where F: FnOnce(u32) -> T
+    {
+        match self.decode_variant(seq, surrogates) {
+            Ok(cp) => Some(f(cp)),
+            Err(state) => self.fail(seq, state, replacement),
+        }
+    }
+    
+    unsafe fn decode_variant(&mut self, seq: * const u8, surrogates: bool) -> Result<u32, u8> {
+        let dfa = self.variant.dfa();
+        // Classified by the byte that follows, as in `chk_err!`, but never
+        // consuming anything past `end`.
+        let (res, stop) = match decode_at(seq, self.limit, &dfa) {
+            (_, stop) if stop > self.end => (Err(0), self.end),
+            res => res,
+        };
+        self.next = stop;
+        
+        match res {
+            Ok(high @ 0xD800..=0xDBFF) if stop < self.end => {
+                match decode_at(stop, self.end, &dfa) {
+                    (Ok(low @ 0xDC00..=0xDFFF), stop) => {
+                        self.next = stop;
+                        if dfa.pairs { Ok(combine_surrogates(high, low)) } else { Err(ES) }
+                    },
+                    _ if dfa.pairs || !surrogates => Err(ES),
+                    _ => Ok(high),
+                }
+            },
+            Ok(0xD800..=0xDFFF) if dfa.pairs || !surrogates => Err(ES),
+            res => res,
+        }
+    }
+    
+    // 
+    // An ill-formed sequence is reported once, when its first byte is escaped.
+    // `escaped` then marks the end of the bytes still to be escaped. In WTF-8,
+    // lone surrogates in the escape range are escaped as well, so that they
+    // cannot be confused with escaped bytes.
+    // 
+    
+    fn next_escaped<T, F>(&mut self, f: F) -> Option<T> where F: FnOnce(u32) -> T {
+        unsafe {
+            if self.next >= self.escaped {
+                if self.next >= self.end { return None; }
+                
+                let seq = self.next;
+                let surrogates = self.variant == Utf8Variant::Wtf8;
+                match self.decode_variant(seq, surrogates) {
+                    Ok(0xDC80..=0xDCFF) => { let _ = self.fail(seq, ES, ()); },
+                    Ok(cp) => return Some(f(cp)),
+                    Err(state) => { let _ = self.fail(seq, state, ()); },
+                }
+                
+                self.escaped = self.next;
+                self.next = seq;
+            }
+            
+            let byte = next_b!(self);
+            Some(f(0xDC00 + byte as u32))
+        }
+    }
+    
+    /// Reports the ill-formed sequence from `seq` to `next`, and resumes
+    /// decoding after it.
+    #[cold]
+    unsafe fn fail<T>(&mut self, seq: * const u8, state: u8, replacement: T) -> Option<T> {
+        self.first = self.next;
+        let offset = (seq as usize) - (self.start as usize);
+        let bytes = from_raw_parts(seq, (self.next as usize) - (seq as usize));
+        self.status = Err(Utf8Error::new(error_kind(state), offset, bytes));
+        
+        match self.mode {
+            ErrorMode::Report => None,
+            ErrorMode::Replace | ErrorMode::Escape => Some(replacement),
+        }
+    }
+    
+    /// Returns what `next_char` would, without consuming anything or changing
+    /// `status()`.
+    pub fn peek_char(&mut self) -> Option<char> {
+        self.peek_nth_and_category(0).map(|(c, _)| c)
+    }
+    
+    pub fn peek_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+        self.peek_nth_and_category(0)
+    }
+    
+    /// Returns what the `n+1`th call to `next_char` from here would. The next
+    /// few results are cached, and stay cached as they are consumed.
+    pub fn peek_nth(&mut self, n: usize) -> Option<char> {
+        self.peek_nth_and_category(n).map(|(c, _)| c)
+    }
+    
+    pub fn peek_nth_and_category(&mut self, n: usize) -> Option<(char, GeneralCategory)> {
+        let (next, end, peek) = (self.next, self.end, &mut self.peek);
+        if peek.start != next || peek.end != end {
+            // Keep what is still ahead if the cached characters were consumed.
+            let consumed = peek.items[..peek.len].iter().position(|item| item.1 == next);
+            match consumed {
+                Some(i) if peek.end == end => {
+                    peek.items.copy_within(i + 1..peek.len, 0);
+                    peek.len -= i + 1;
+                },
+                _ => peek.len = 0,
+            }
+            peek.start = next;
+            peek.end = end;
+        }
+        if n < peek.len { return peek.items[n].0; }
+        
+        let mut ahead = self.clone();
+        ahead.lines = None;
+        if let Some(&(_, end)) = self.peek.items[..self.peek.len].last() { ahead.next = end; }
+        for i in self.peek.len..=n {
+            let item = ahead.next_char_and_category();
+            if i < PEEK_CACHE {
+                self.peek.items[i] = (item, ahead.next);
+                self.peek.len += 1;
+            }
+            if i == n { return item; }
+        }
+        
+        unreachable!()
+    }
+    
+    /// Consumes characters as long as their category is in `cats`. Stops
+    /// before an ill-formed sequence, leaving it for `next_char` to report.
+    pub fn skip_while_category(&mut self, cats: GeneralCategory) {
+        track_lines!(self, if self.variant != Utf8Variant::Standard {
+            self.skip_while_variant(|c| general_category(c) & cats != 0)
+        } else {
+            scan_while!(self, CAT_VALUES, CAT_INDEX, |cat| (1 << cat) as GeneralCategory & cats != 0)
+        })
+    }
+    
+    /// Like `skip_while_category`, but returns the consumed characters.
+    pub fn take_while_category(&mut self, cats: GeneralCategory) -> &'a str {
+        let start = self.next;
+        self.skip_while_category(cats);
+        unsafe { from_utf8_unchecked(from_raw_parts(start, (self.next as usize) - (start as usize))) }
+    }
+    
+    /// Consumes characters as long as their script is `script`. Stops before
+    /// an ill-formed sequence, leaving it for `next_char` to report.
+    pub fn skip_while_script(&mut self, script: Script) {
+        track_lines!(self, if self.variant != Utf8Variant::Standard {
+            self.skip_while_variant(|c| self::script(c) == script)
+        } else {
+            scan_while!(self, SCRIPT_VALUES, SCRIPT_INDEX, |scr| scr == script as u8)
+        })
+    }
+    
+    /// Like `skip_while_script`, but returns the consumed characters.
+    pub fn take_while_script(&mut self, script: Script) -> &'a str {
+        let start = self.next;
+        self.skip_while_script(script);
+        unsafe { from_utf8_unchecked(from_raw_parts(start, (self.next as usize) - (start as usize))) }
+    }
+    
+    // The variants also stop before sequences that are not standard UTF-8, so
+    // that what was consumed can be returned as a `str`.
+    fn skip_while_variant<F: Fn(char) -> bool>(&mut self, pred: F) {
+        unsafe {
+            while self.next < self.end {
+                let seq = self.next;
+                let c = match self.decode_variant(seq, false) {
+                    Ok(cp) => from_u32_unchecked(cp),
+                    Err(_) => { self.next = seq; break; },
+                };
+                
+                if (self.next as usize) - (seq as usize) != c.len_utf8() || !pred(c) {
+                    self.next = seq;
+                    break;
+                }
+            }
+        }
+    }
+    
+    /// Consumes the longest run of ASCII characters at the current position,
+    /// scanning many bytes at a time where the target supports it. This is the
+    /// only decoding method that does; `next_char` and its siblings do not.
+    pub fn take_ascii(&mut self) -> &'a str {
+        track_lines!(self, unsafe {
+            let rest = from_raw_parts(self.next, (self.end as usize) - (self.next as usize));
+            let run = &rest[..ascii_prefix_len(rest)];
+            self.next = self.next.add(run.len());
+            from_utf8_unchecked(run)
+        })
+    }
+    
+    /// Decodes the last remaining character, shrinking the input from the
+    /// back. Ill-formed sequences are detected and reported exactly as
+    /// `next_char` would report them when reaching the same bytes.
+    pub fn prev_char(&mut self) -> Option<char> {
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let (seq, res) = self.prev_seq();
+            match res {
+                Ok(c) => { self.end = seq; Some(c) },
+                Err(state) => self.set_err_back(seq, state, REPLACEMENT_CHARACTER),
+            }
+        }
+    }
+    
+    pub fn prev_char_and_category(&mut self) -> Option<(char, GeneralCategory)> {
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let (seq, res) = self.prev_seq();
+            match res {
+                Ok(c) => { self.end = seq; Some((c, general_category(c))) },
+                Err(state) => {
+                    self.set_err_back(seq, state, (REPLACEMENT_CHARACTER, OTHER_SYMBOL))
+                },
+            }
+        }
+    }
+    
+    pub fn prev_char_and_script(&mut self) -> Option<(char, Script)> {
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let (seq, res) = self.prev_seq();
+            match res {
+                Ok(c) => { self.end = seq; Some((c, script(c))) },
+                Err(state) => {
+                    self.set_err_back(seq, state, (REPLACEMENT_CHARACTER, Script::Common))
+                },
+            }
+        }
+    }
+    
+    pub fn prev_char_and_properties(&mut self) -> Option<(char, GeneralCategory, Script)> {
+        if self.next >= self.end { return None; }
+        
+        unsafe {
+            let (seq, res) = self.prev_seq();
+            match res {
+                Ok(c) => {
+                    let (cat, scr) = props_of(c);
+                    self.end = seq;
+                    Some((c, cat, scr))
+                },
+                Err(state) => {
+                    let replacement = (REPLACEMENT_CHARACTER, OTHER_SYMBOL, Script::Common);
+                    self.set_err_back(seq, state, replacement)
+                },
+            }
+        }
+    }
+    
+    // 
+    // Forward decoding splits the input into characters and maximal subparts,
+    // each of which starts with the first non-continuation byte it contains,
+    // and has at most three continuation bytes. So the last one ends at `end`
+    // and starts at the closest preceding non-continuation byte, unless
+    // decoding from there stops short, leaving a stray continuation byte.
+    // 
+    // Decoding may look past `end` up to `limit`, so that a sequence is
+    // classified by the byte that actually follows it in the input.
+    // 
+    // In the variants accepting surrogates, a high surrogate sequence always
+    // starts a new sequence when decoding forward, so a low surrogate pairs up
+    // with the three bytes before it if they decode to one. In WTF-8, such a
+    // pair is one ill-formed sequence.
+    // 
+    
+    unsafe fn prev_seq(&self) -> (* const u8, Result<char, u8>) {
+        let dfa = self.variant.dfa();
+        let last = self.end.offset(-1);
+        let mut seq = last;
+        while seq > self.next && (last as usize) - (seq as usize) < 3
+            && (*seq & 0xC0) == 0x80
+        {
+            seq = seq.offset(-1);
+        }
+        
+        let (res, stop) = decode_at(seq, self.limit, &dfa);
+        if stop != self.end { return (last, Err(EL)); }
+        
+        match res {
+            Ok(low @ 0xDC00..=0xDFFF) if (seq as usize) - (self.next as usize) >= 3 => {
+                let high_seq = seq.offset(-3);
+                match decode_at(high_seq, self.limit, &dfa) {
+                    (Ok(high @ 0xD800..=0xDBFF), stop) if stop == seq => if dfa.pairs {
+                        (high_seq, Ok(from_u32_unchecked(combine_surrogates(high, low))))
+                    } else {
+                        (high_seq, Err(ES))
+                    },
+                    _ => (seq, Err(ES)),
+                }
+            },
+            Ok(0xD800..=0xDFFF) => (seq, Err(ES)),
+            Ok(cp) => (seq, Ok(from_u32_unchecked(cp))),
+            Err(state) => (seq, Err(state)),
+        }
+    }
+    
+    unsafe fn set_err_back<T>(&mut self, seq: * const u8, state: u8, replacement: T) -> Option<T> {
+        let offset = (seq as usize) - (self.start as usize);
+        let bytes = from_raw_parts(seq, (self.end as usize) - (seq as usize));
+        self.status = Err(Utf8Error::new(error_kind(state), offset, bytes));
+        self.end = seq;
+        
+        match self.mode {
+            ErrorMode::Report => None,
+            ErrorMode::Replace | ErrorMode::Escape => Some(replacement),
+        }
+    }
+    
+    pub fn mark(&self) -> Utf8DecoderPosition<'a> {
+        Utf8DecoderPosition {
+            start: self.start,
+            limit: self.limit,
+            raw: self.next,
+            first: self.first,
+            variant: self.variant,
+            nonstandard: self.nonstandard,
+            escaped: self.escaped,
+            status: self.status,
+            lines: self.lines,
+            _buf: PhantomData,
+        }
+    }
+    
+    /// Moves the decoder back or forward to `mark`, which may point into input
+    /// that was already consumed, but not past where `prev_char` has shrunk
+    /// the input to. Errors reported after `mark` was taken are forgotten, as
+    /// if decoding had never gone past it, and `try_get_marked_string` accepts
+    /// the same marks it did then. Line tracking is restored to its state at
+    /// `mark` as well.
+    /// 
+    /// Panics if `mark` was taken under a different `Utf8Variant`, or by a
+    /// decoder over different input.
+    pub fn reset_to(&mut self, mark: Utf8DecoderPosition<'a>) {
+        self.check_mark(&mark);
+        assert!(mark.raw <= self.end);
+        assert!(mark.variant == self.variant, "mark was taken under a different variant");
+        
+        // What the mark's decoder accepted before it was taken is only known
+        // to be valid in the variants it has been set to.
+        self.nonstandard |= mark.nonstandard;
+        self.next = mark.raw;
+        self.first = mark.first;
+        self.escaped = mark.escaped;
+        self.status = mark.status;
+        self.lines = mark.lines;
+    }
+    
+    /// Marks taken by a decoder over any other slice, even an overlapping one,
+    /// could put the boundaries of a string in the middle of a sequence.
+    fn check_mark(&self, mark: &Utf8DecoderPosition<'a>) {
+        assert!(mark.start == self.start && mark.limit == self.limit,
+            "mark was taken by a decoder over different input");
+    }
+    
+    /// Starts counting lines and columns from the current position, which is
+    /// line 0, column 0. Lines end at LF, CR, CRLF, NEL, and the characters in
+    /// the `Zl` and `Zp` categories; each ill-formed sequence is one column.
+    pub fn track_lines(&mut self, unit: ColumnUnit) {
+        let position = LineColumn { line: 0, column: 0 };
+        self.lines = Some(LineTracker { unit, position, after_cr: false });
+    }
+    
+    /// The line and column of the current position, if `track_lines` was
+    /// called.
+    pub fn line_column(&self) -> Option<LineColumn> {
+        self.lines.map(|lines| lines.position)
+    }
+    
+    // 
+    // Rather than slowing down every decoding loop, the tracker re-decodes
+    // whatever was consumed since it last caught up, whenever tracking is on.
+    // Sequences are split up the same way decoding them did, since decoding
+    // stops at the end of whatever was consumed.
+    // 
+    
+    #[cold]
+    fn advance_lines(&mut self, from: * const u8) {
+        let lines = match self.lines { Some(ref mut lines) => lines, None => return };
+        let dfa = self.variant.dfa();
+        let mut after_high = false;
+        
+        let mut p = from;
+        while p < self.next {
+            let (res, stop) = unsafe { decode_at(p, self.next, &dfa) };
+            let len = (stop as usize) - (p as usize);
+            match res {
+                // The low half of a surrogate pair is part of the same char,
+                // or of the same ill-formed sequence in WTF-8.
+                Ok(0xDC00..=0xDFFF) if after_high => {
+                    lines.position.column += match lines.unit {
+                        ColumnUnit::Chars => 0,
+                        ColumnUnit::Utf16Units => if dfa.pairs { 1 } else { 0 },
+                        ColumnUnit::Bytes => len,
+                    };
+                },
+                Ok(cp) => lines.advance(cp, len),
+                Err(_) => lines.advance(REPLACEMENT_CHARACTER as u32, len),
+            }
+            
+            after_high = matches!(res, Ok(0xD800..=0xDBFF));
+            p = stop;
+        }
+    }
+    
+    /// Returns the input decoded since `mark` was taken, or the error that was
+    /// reported last if it occurred after that. Once the decoder has been set
+    /// to any other variant than `Utf8Variant::Standard`, the marked bytes are
+    /// also checked to be UTF-8, since they may hold what that variant accepted.
+    /// 
+    /// Panics if `mark` was taken by a decoder over different input.
+    pub fn try_get_marked_string(&self, mark: Utf8DecoderPosition<'a>)
+      -> Result<&'a str, Utf8Error>
+    {
+        self.check_mark(&mark);
+        match self.status {
+            Ok(_) => {
+                assert!(self.first <= mark.raw && mark.raw <= self.next);
+            },
+            Err(e) => if mark.raw < self.first {
+                return Err(e);
+            } else {
+                assert!(mark.raw <= self.next);
+            }
+        }
+        
+        let size = (self.next as usize) - (mark.raw as usize);
+        let bytes = unsafe { from_raw_parts(mark.raw, size) };
+        if self.nonstandard {
+            if let Err(mut e) = validate(bytes) {
+                e.offset += (mark.raw as usize) - (self.start as usize);
+                return Err(e);
+            }
+        }
+        
+        unsafe { Ok(from_utf8_unchecked(bytes)) }
+    }
+}
+
+/// Encodes a code point returned by `next_code_point` in `ErrorMode::Escape`,
+/// restoring escaped bytes. Other surrogates are encoded as in WTF-8, so that
+/// input decoded as standard UTF-8 or WTF-8 round-trips exactly.
+/// 
+/// Returns `None` if `cp` is not a code point.
+pub fn encode_escaped(cp: u32, buf: &mut [u8; 4]) -> Option<&[u8]> {
+    let len = match cp {
+        0xDC80..=0xDCFF => { buf[0] = cp as u8; 1 },
+        0x0000..=0x007F => { buf[0] = cp as u8; 1 },
+        0x0080..=0x07FF => {
+            buf[0] = 0xC0 | (cp >> 6) as u8;
+            buf[1] = 0x80 | (cp & 0x3F) as u8;
+            2
+        },
+        0x0800..=0xFFFF => {
+            buf[0] = 0xE0 | (cp >> 12) as u8;
+            buf[1] = 0x80 | ((cp >> 6) & 0x3F) as u8;
+            buf[2] = 0x80 | (cp & 0x3F) as u8;
+            3
+        },
+        0x10000..=0x10FFFF => {
+            buf[0] = 0xF0 | (cp >> 18) as u8;
+            buf[1] = 0x80 | ((cp >> 12) & 0x3F) as u8;
+            buf[2] = 0x80 | ((cp >> 6) & 0x3F) as u8;
+            buf[3] = 0x80 | (cp & 0x3F) as u8;
+            4
+        },
+        _ => return None,
+    };
+    
+    Some(&buf[..len])
+}
+
+// 
+// Iterator Adapters
+// 
+
+macro_rules! decoder_iter {
+    ($(#[$attr:meta])* $name:ident -> $item:ty, |$d:ident| $next:expr, $back:expr) => {
+        $(#[$attr])*
+        pub struct $name<'d, 'a> {
+            decoder: &'d mut Utf8Decoder<'a>,
+            done: bool,
+        }
+        
+        impl<'d, 'a> Iterator for $name<'d, 'a> {
+            type Item = $item;
+            
+            fn next(&mut self) -> Option<$item> {
+                if self.done { return None; }
+                
+                let $d = &mut *self.decoder;
+                let item = $next;
+                self.done = item.is_none();
+                item
+            }
+            
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.done { (0, Some(0)) } else { self.decoder.size_hint() }
+            }
+            
+            // Every ASCII byte is one item, so runs of them are counted with
+            // `take_ascii` instead of being decoded one by one.
+            fn count(self) -> usize {
+                if self.done { return 0; }
+                
+                let $d = self.decoder;
+                let mut count = 0;
+                loop {
+                    count += $d.take_ascii().len();
+                    if $next.is_none() { return count; }
+                    count += 1;
+                }
+            }
+        }
+        
+        impl<'d, 'a> DoubleEndedIterator for $name<'d, 'a> {
+            fn next_back(&mut self) -> Option<$item> {
+                if self.done { return None; }
+                
+                let $d = &mut *self.decoder;
+                let item = $back;
+                self.done = item.is_none();
+                item
+            }
+        }
+        
+        impl<'d, 'a> FusedIterator for $name<'d, 'a> {}
+    }
+}
+
+decoder_iter! {
+    /// Iterator returned by `Utf8Decoder::chars`.
+    Chars -> char, |d| d.next_char(), d.prev_char()
+}
+
+decoder_iter! {
+    /// Iterator returned by `Utf8Decoder::chars_with_category`.
+    CharsWithCategory -> (char, GeneralCategory), |d| d.next_char_and_category(),
+    d.prev_char_and_category()
+}
+
+decoder_iter! {
+    /// Iterator returned by `Utf8Decoder::chars_with_script`.
+    CharsWithScript -> (char, Script), |d| d.next_char_and_script(),
+    d.prev_char_and_script()
+}
+
+decoder_iter! {
+    /// Iterator returned by `Utf8Decoder::chars_with_properties`.
+    CharsWithProperties -> (char, GeneralCategory, Script), |d| d.next_char_and_properties(),
+    d.prev_char_and_properties()
+}
+
+decoder_iter! {
+    /// Iterator returned by `Utf8Decoder::char_indices`.
+    CharIndices -> (usize, char), |d| {
+        let offset = d.offset();
+        d.next_char().map(|c| (offset, c))
+    }, {
+        let c = d.prev_char();
+        c.map(|c| ((d.end as usize) - (d.start as usize), c))
+    }
+}
+
+// The adapters borrow the decoder rather than consuming it, so `status()` can
+// tell whether iteration stopped at the end of the input or at an error. In
+// `ErrorMode::Report`, an error on either end stops iteration on both.
+impl<'a> Utf8Decoder<'a> {
+    /// Iterates over the remaining characters. In `ErrorMode::Report`, the
+    /// iterator ends at the first ill-formed sequence.
+    pub fn chars(&mut self) -> Chars<'_, 'a> {
+        Chars { decoder: self, done: false }
+    }
+    
+    pub fn chars_with_category(&mut self) -> CharsWithCategory<'_, 'a> {
+        CharsWithCategory { decoder: self, done: false }
+    }
+    
+    pub fn chars_with_script(&mut self) -> CharsWithScript<'_, 'a> {
+        CharsWithScript { decoder: self, done: false }
+    }
+    
+    pub fn chars_with_properties(&mut self) -> CharsWithProperties<'_, 'a> {
+        CharsWithProperties { decoder: self, done: false }
+    }
+    
+    /// Like `chars`, but also yields the byte offset of each character into
+    /// the input.
+    pub fn char_indices(&mut self) -> CharIndices<'_, 'a> {
+        CharIndices { decoder: self, done: false }
+    }
+    
+    fn offset(&self) -> usize {
+        (self.next as usize) - (self.start as usize)
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A surrogate pair takes six bytes for a single character, or for a
+        // single ill-formed sequence in WTF-8.
+        let remaining = (self.end as usize) - (self.next as usize);
+        let longest = if self.variant == Utf8Variant::Standard { 4 } else { 6 };
+        match self.mode {
+            ErrorMode::Report => (0, Some(remaining)),
+            ErrorMode::Replace | ErrorMode::Escape => (remaining.div_ceil(longest), Some(remaining)),
+        }
+    }
+}
+
+// 
+// Chunked Iteration
+// 
+
+/// A run of valid UTF-8, followed by the ill-formed sequence that ended it,
+/// if any. Only the last chunk of the input has an empty `invalid` part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utf8Chunk<'a> {
+    valid: &'a str,
+    invalid: &'a [u8],
+    error: Option<Utf8Error>,
+}
+
+impl<'a> Utf8Chunk<'a> {
+    pub fn valid(&self) -> &'a str {
+        self.valid
+    }
+    
+    /// The maximal subpart of an ill-formed sequence following `valid`.
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+    
+    /// The error describing `invalid`, or `None` if it is empty.
+    pub fn error(&self) -> Option<Utf8Error> {
+        self.error
+    }
+}
+
+/// Iterator returned by `utf8_chunks`.
+#[derive(Clone)]
+pub struct Utf8Chunks<'a> {
+    decoder: Utf8Decoder<'a>,
+}
+
+/// Splits `raw` into alternating valid and invalid parts, like
+/// `String::from_utf8_lossy` would before replacing the latter.
+pub fn utf8_chunks(raw: &[u8]) -> Utf8Chunks<'_> {
+    Utf8Chunks { decoder: decode_utf8(raw) }
+}
+
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = Utf8Chunk<'a>;
+    
+    fn next(&mut self) -> Option<Utf8Chunk<'a>> {
+        let d = &mut self.decoder;
+        if d.next >= d.end { return None; }
+        
+        let mark = d.mark();
+        loop {
+            d.take_ascii();
+            if d.next >= d.end {
+                let valid = d.try_get_marked_string(mark).unwrap();
+                return Some(Utf8Chunk { valid, invalid: &[], error: None });
+            }
+            
+            let seq = d.mark();
+            if d.next_char().is_none() {
+                let error = d.status.unwrap_err();
+                let valid = unsafe {
+                    let size = (seq.raw as usize) - (mark.raw as usize);
+                    from_utf8_unchecked(from_raw_parts(mark.raw, size))
+                };
+                let invalid = unsafe {
+                    from_raw_parts(seq.raw, (d.next as usize) - (seq.raw as usize))
+                };
+                return Some(Utf8Chunk { valid, invalid, error: Some(error) });
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Utf8Chunks<'a> {}
+
+/// Iterator returned by `utf8_errors`.
+#[derive(Clone)]
+pub struct Utf8Errors<'a> {
+    chunks: Utf8Chunks<'a>,
+}
+
+/// Iterates over every ill-formed sequence in `raw`, skipping ASCII runs many
+/// bytes at a time.
+pub fn utf8_errors(raw: &[u8]) -> Utf8Errors<'_> {
+    Utf8Errors { chunks: utf8_chunks(raw) }
+}
+
+impl<'a> Iterator for Utf8Errors<'a> {
+    type Item = Utf8Error;
+    
+    fn next(&mut self) -> Option<Utf8Error> {
+        self.chunks.next().and_then(|chunk| chunk.error)
+    }
+}
+
+impl<'a> FusedIterator for Utf8Errors<'a> {}
+
+/// Collects the ill-formed sequences in `raw`, stopping after `cap` of them
+/// if given. Returns an empty list for valid UTF-8.
+#[cfg(feature = "std")]
+pub fn validate_all(raw: &[u8], cap: Option<usize>) -> Vec<Utf8Error> {
+    let errors = utf8_errors(raw);
+    match cap {
+        Some(cap) => errors.take(cap).collect(),
+        None => errors.collect(),
+    }
+}
+
+// 
+// Lossy Conversion
+// 
+
+/// How many offsets of ill-formed sequences a `Utf8Report` keeps.
+pub const REPORTED_ERROR_OFFSETS: usize = 8;
+
+/// Statistics on the ill-formed sequences found in some input.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Utf8Report {
+    counts: [usize; 6],
+    offsets: [usize; REPORTED_ERROR_OFFSETS],
+}
+
+impl Utf8Report {
+    /// Counts one more ill-formed sequence.
+    pub fn add(&mut self, error: &Utf8Error) {
+        let total = self.total();
+        if total < REPORTED_ERROR_OFFSETS { self.offsets[total] = error.offset; }
+        self.counts[error.kind as usize] += 1;
+    }
+    
+    /// The number of ill-formed sequences of the given kind.
+    pub fn count(&self, kind: Utf8ErrorKind) -> usize {
+        self.counts[kind as usize]
+    }
+    
+    /// The number of ill-formed sequences of any kind.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+    
+    /// The byte offsets of the first `REPORTED_ERROR_OFFSETS` ill-formed
+    /// sequences.
+    pub fn error_offsets(&self) -> &[usize] {
+        &self.offsets[..self.total().min(REPORTED_ERROR_OFFSETS)]
+    }
+}
+
+/// Converts `raw` to a string, replacing ill-formed sequences with U+FFFD
+/// exactly like `String::from_utf8_lossy`, and reports what was replaced.
+/// Only allocates if there is something to replace.
+#[cfg(feature = "std")]
+pub fn decode_utf8_lossy(raw: &[u8]) -> (Cow<'_, str>, Utf8Report) {
+    let mut report = Utf8Report::default();
+    let mut chunks = utf8_chunks(raw);
+    
+    let first = match chunks.next() {
+        Some(chunk) => chunk,
+        None => return (Cow::Borrowed(""), report),
+    };
+    let error = match first.error {
+        Some(error) => error,
+        None => return (Cow::Borrowed(first.valid), report),
+    };
+    
+    let mut string = String::with_capacity(raw.len() + 2);
+    string.push_str(first.valid);
+    string.push(REPLACEMENT_CHARACTER);
+    report.add(&error);
+    
+    for chunk in chunks {
+        string.push_str(chunk.valid);
+        if let Some(error) = chunk.error {
+            string.push(REPLACEMENT_CHARACTER);
+            report.add(&error);
+        }
+    }
+    
+    (Cow::Owned(string), report)
+}
+
+// 
+// Streaming Decoder
+// 
+
+/// Decodes UTF-8 that arrives in chunks, such as successive reads from a file
+/// or socket. The DFA state and any incomplete sequence at the end of a chunk
+/// carry over into the next one, and error offsets count from the start of
+/// the stream rather than the current chunk.
+#[derive(Clone)]
+pub struct Utf8StreamDecoder {
+    status: Result<(), Utf8Error>,
+    mode: ErrorMode,
+    state: u8,
+    codepoint: u32,
+    offset: usize,
+    partial: [u8; 4],
+    partial_len: u8,
+}
+
+pub fn decode_utf8_stream() -> Utf8StreamDecoder {
+    Utf8StreamDecoder {
+        status: Ok(()),
+        mode: ErrorMode::Report,
+        state: OK,
+        codepoint: 0,
+        offset: 0,
+        partial: [0; 4],
+        partial_len: 0,
+    }
+}
+
+/// Iterator over the characters completed by one chunk of a stream.
+/// 
+/// Ill-formed sequences are yielded as `Err` in `ErrorMode::Report`, and as
+/// U+FFFD in `ErrorMode::Replace`. Bytes left unvisited when the iterator is
+/// dropped are not consumed.
+pub struct Utf8StreamChunk<'s, 'a> {
+    stream: &'s mut Utf8StreamDecoder,
+    chunk: &'a [u8],
+}
+
+impl Utf8StreamDecoder {
+    pub fn status(&self) -> Result<(), Utf8Error> {
+        self.status
+    }
+    
+    pub fn set_error_mode(&mut self, mode: ErrorMode) {
+        self.mode = mode;
+    }
+    
+    /// The number of bytes consumed so far, including those of an incomplete
+    /// sequence waiting for the next chunk.
+    pub fn offset(&self) -> usize {
+        self.offset + self.partial_len as usize
+    }
+    
+    pub fn feed<'s, 'a>(&'s mut self, chunk: &'a [u8]) -> Utf8StreamChunk<'s, 'a> {
+        Utf8StreamChunk { stream: self, chunk }
+    }
+    
+    /// Ends the stream, reporting an incomplete trailing sequence as
+    /// `UnexpectedEndOfBuffer`. The decoder can be reused afterwards.
+    pub fn finish(&mut self) -> Result<(), Utf8Error> {
+        if self.partial_len == 0 { return Ok(()); }
+        
+        let _ = self.fail(0);
+        self.status
+    }
+    
+    fn fail(&mut self, state: u8) -> Result<char, Utf8Error> {
+        let len = self.partial_len as usize;
+        let err = Utf8Error::new(error_kind(state), self.offset, &self.partial[..len]);
+        
+        self.status = Err(err);
+        self.offset += len;
+        self.partial_len = 0;
+        self.state = OK;
+        
+        match self.mode {
+            ErrorMode::Report => Err(err),
+            ErrorMode::Replace | ErrorMode::Escape => Ok(REPLACEMENT_CHARACTER),
+        }
+    }
+}
+
+impl<'s, 'a> Iterator for Utf8StreamChunk<'s, 'a> {
+    type Item = Result<char, Utf8Error>;
+    
+    fn next(&mut self) -> Option<Result<char, Utf8Error>> {
+        let this = &mut *self.stream;
+        
+        while let Some((&byte, rest)) = self.chunk.split_first() {
+            let class = CHAR_CLASSES[byte as usize];
+            
+            if this.state == OK {
+                self.chunk = rest;
+                if byte < 0x80 {
+                    this.offset += 1;
+                    return Some(Ok(byte as char));
+                }
+                
+                this.codepoint = (0xFFu32 >> class) & (byte as u32);
+                this.state = NEXT_STATE[class as usize];
+                this.partial[0] = byte;
+                this.partial_len = 1;
+                
+                if this.state >= EL { return Some(this.fail(this.state)); }
+            } else {
+                let state = NEXT_STATE[(this.state + class) as usize];
+                if state >= EL { return Some(this.fail(state)); }
+                
+                self.chunk = rest;
+                this.codepoint = (byte as u32 & 0x3F) | (this.codepoint << 6);
+                this.partial[this.partial_len as usize] = byte;
+                this.partial_len += 1;
+                this.state = state;
+                
+                if state == OK {
+                    this.offset += this.partial_len as usize;
+                    this.partial_len = 0;
+                    return Some(Ok(unsafe { from_u32_unchecked(this.codepoint) }));
+                }
+            }
+        }
+        
+        None
+    }
+}
@@ -1,24 +1,51 @@
+//! Decoders for UTF-8 and a few other encodings that can classify characters
+//! by Unicode properties while decoding them.
+//! 
+//! `Utf8Decoder::next_char` and the other `next_char_*` methods decode ASCII
+//! one byte at a time, like everything else. Only `Utf8Decoder::take_ascii`
+//! skips ASCII runs 16 or 32 bytes at a time, so call it between them where
+//! the input is mostly ASCII. `decode_utf8::validate`, `utf8_chunks`,
+//! `utf8_errors` and the `count` of the iterator adapters do so internally.
+
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+#[macro_use]
+extern crate std;
+
 pub mod decode_utf8;
+#[macro_use]
+mod code_units;
+pub mod decode_utf16;
+pub mod decode_utf32;
+pub mod decode_single_byte;
+pub mod char_decoder;
+mod ascii;
 mod tables;
 
 #[cfg(test)]
 mod tests {
+    use std::prelude::v1::*;
     use std::char::from_u32;
     use std::str::from_utf8;
     use decode_utf8::*;
+    use decode_utf16::*;
+    use decode_utf32::*;
+    use decode_single_byte::*;
+    use char_decoder::CharDecoder;
     
     macro_rules! err_tests {
         ($($name:ident: $expected_err:expr, $dat:expr,)*) => {
         $(
             #[test]
             fn $name() {
-                use self::Utf8Error::*;
+                use self::Utf8ErrorKind::*;
                 
                 let bytes = $dat;
                 let mut iter = decode_utf8(&bytes);
                 
                 assert!(iter.next_char().is_none());
-                assert_eq!(iter.status().unwrap_err(), $expected_err);
+                assert_eq!(iter.status().unwrap_err().kind(), $expected_err);
             }
         )*
         }
@@ -62,8 +89,8 @@ mod tests {
         assert_eq!(cat, CONTROL);
         
         let cat = iter.next_char_and_category().unwrap().1;
-        assert!(cat.subset_of(SEPERATOR));
-        assert_eq!(cat, SPACE_SEPERATOR);
+        assert!(cat.subset_of(SEPARATOR));
+        assert_eq!(cat, SPACE_SEPARATOR);
         
         let cat = iter.next_char_and_category().unwrap().1;
         assert!(cat.subset_of(PUNCTUATION));
@@ -96,7 +123,7 @@ mod tests {
         for range in pau_ranges.iter() {
             for codepoint in range.0..range.1+1 {
                 s.push(from_u32(codepoint).unwrap());
-                let mut iter = decode_utf8(s.as_str().as_bytes());
+                let mut iter = decode_utf8(s.as_bytes());
                 let (char, script) = iter.next_char_and_script().unwrap();
                 assert_eq!(char as u32, codepoint); assert_eq!(script, Unknown);
                 assert!(iter.next_char().is_none());
@@ -123,7 +150,7 @@ mod tests {
         
         for (codepoint, expected) in examples.iter() {
             s.push(from_u32(*codepoint).unwrap());
-            let mut iter = decode_utf8(s.as_str().as_bytes());
+            let mut iter = decode_utf8(s.as_bytes());
             let (char, script) = iter.next_char_and_script().unwrap();
             assert_eq!(char as u32, *codepoint); assert_eq!(script, *expected);
             assert!(iter.next_char().is_none());
@@ -160,4 +187,1311 @@ mod tests {
         assert!(iter.try_get_marked_string(before_error).is_err());
         
     }
+    
+    #[test]
+    fn accepts_empty_input() {
+        let mut iter = decode_utf8(&[]);
+        assert!(iter.next_char().is_none());
+        assert!(iter.next_char_and_category().is_none());
+        assert!(iter.next_char_and_script().is_none());
+        assert!(iter.status().is_ok());
+        
+        let start = iter.mark();
+        assert_eq!(iter.try_get_marked_string(start).unwrap(), "");
+    }
+    
+    #[test]
+    fn marked_strings_outlive_decoder() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        
+        let bytes = b"hello world";
+        let word = {
+            let mut iter = decode_utf8(bytes);
+            assert_send_sync(&iter);
+            
+            let start = iter.mark();
+            for _ in 0..5 { iter.next_char(); }
+            iter.try_get_marked_string(start).unwrap()
+        };
+        
+        assert_eq!(word, "hello");
+    }
+    
+    #[test]
+    fn replaces_maximal_subparts() {
+        let inputs: [&[u8]; 5] = [
+            &[0x61, 0xF1, 0x80, 0x80, 0xE1, 0x80, 0xC2, 0x62, 0x80, 0x63, 0x80, 0xBF, 0x64],
+            &[0xC0, 0xAF, 0xE0, 0x80, 0xBF, 0xF0, 0x81, 0x82, 0x41],
+            &[0xED, 0xA0, 0x80, 0xED, 0xBF, 0xBF, 0xED, 0xAF, 0x41],
+            &[0xF4, 0x91, 0x92, 0x93, 0xFF, 0x41, 0x80, 0xBF, 0x42],
+            &[0xE1, 0x80, 0xE2, 0xF0, 0x91, 0x92, 0xF1, 0xBF, 0x41],
+        ];
+        
+        for bytes in inputs.iter() {
+            let mut iter = decode_utf8(bytes);
+            iter.set_error_mode(ErrorMode::Replace);
+            
+            let mut s = String::new();
+            while let Some(c) = iter.next_char() { s.push(c); }
+            assert_eq!(s, String::from_utf8_lossy(bytes));
+            assert!(iter.status().is_err());
+        }
+        
+        let mut iter = decode_utf8(&[0x80, 0xC2]);
+        iter.set_error_mode(ErrorMode::Replace);
+        assert_eq!(iter.next_char_and_category(), Some(('\u{FFFD}', OTHER_SYMBOL)));
+        assert_eq!(iter.next_char_and_script(), Some(('\u{FFFD}', Script::Common)));
+        assert_eq!(iter.status().unwrap_err().kind(), Utf8ErrorKind::UnexpectedEndOfBuffer);
+        assert!(iter.next_char().is_none());
+    }
+    
+    #[test]
+    fn resyncs_after_reporting_errors() {
+        let mut iter = decode_utf8(&[0xC2, 0x41, 0xE0, 0x80, 0x42]);
+        
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.status().unwrap_err().kind(), Utf8ErrorKind::NotAContinuationByte);
+        assert_eq!(iter.next_char(), Some('A'));
+        
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.status().unwrap_err().kind(), Utf8ErrorKind::OverlongEncoding);
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.status().unwrap_err().kind(), Utf8ErrorKind::NotALeadingByte);
+        assert_eq!(iter.next_char(), Some('B'));
+        assert!(iter.next_char().is_none());
+    }
+    
+    #[test]
+    fn locates_errors() {
+        let mut iter = decode_utf8(b"abc\xE2\x82xyz\xF0\x9F");
+        
+        while iter.next_char().is_some() {}
+        let err = iter.status().unwrap_err();
+        assert_eq!(err.kind(), Utf8ErrorKind::NotAContinuationByte);
+        assert_eq!(err.offset(), 3);
+        assert_eq!(err.error_len(), Some(2));
+        assert_eq!(err.bytes(), &[0xE2, 0x82]);
+        assert_eq!(err.to_string(),
+            "invalid UTF-8 at byte offset 3: missing continuation byte (E2 82)");
+        
+        while iter.next_char().is_some() {}
+        let err = iter.status().unwrap_err();
+        assert_eq!(err.kind(), Utf8ErrorKind::UnexpectedEndOfBuffer);
+        assert_eq!(err.offset(), 8);
+        assert_eq!(err.error_len(), None);
+        assert_eq!(err.bytes(), &[0xF0, 0x9F]);
+    }
+    
+    #[test]
+    fn decodes_chunked_demo_utf8_txt() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let text_content = from_utf8(demo_utf8_txt).unwrap();
+        
+        for chunk_size in 1..8 {
+            let mut stream = decode_utf8_stream();
+            let mut s = String::new();
+            
+            for chunk in demo_utf8_txt.chunks(chunk_size) {
+                for c in stream.feed(chunk) { s.push(c.unwrap()); }
+            }
+            
+            assert!(stream.finish().is_ok());
+            assert_eq!(s, text_content);
+            assert_eq!(stream.offset(), demo_utf8_txt.len());
+        }
+    }
+    
+    #[test]
+    fn reports_stream_errors_at_absolute_offsets() {
+        let mut stream = decode_utf8_stream();
+        
+        let got: Vec<_> = stream.feed(b"ab\xE2").collect();
+        assert_eq!(got, [Ok('a'), Ok('b')]);
+        
+        let got: Vec<_> = stream.feed(b"\x82x\xF0\x9F").collect();
+        assert_eq!(got.len(), 2);
+        let err = got[0].unwrap_err();
+        assert_eq!(err.kind(), Utf8ErrorKind::NotAContinuationByte);
+        assert_eq!((err.offset(), err.bytes()), (2, &[0xE2, 0x82][..]));
+        assert_eq!(got[1], Ok('x'));
+        
+        let err = stream.finish().unwrap_err();
+        assert_eq!(err.kind(), Utf8ErrorKind::UnexpectedEndOfBuffer);
+        assert_eq!((err.offset(), err.bytes()), (5, &[0xF0, 0x9F][..]));
+        assert_eq!(stream.offset(), 7);
+        
+        stream.set_error_mode(ErrorMode::Replace);
+        let got: String = stream.feed(b"\xF0\x9F\x98").map(Result::unwrap).collect();
+        assert_eq!(got, "");
+        let got: String = stream.feed(b"\x80\xC0y").map(Result::unwrap).collect();
+        assert_eq!(got, "\u{1F600}\u{FFFD}y");
+        assert_eq!(stream.status().unwrap_err().offset(), 11);
+    }
+    
+    #[test]
+    fn iterates_like_str() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let text_content = from_utf8(demo_utf8_txt).unwrap();
+        let mut iter = decode_utf8(demo_utf8_txt);
+        
+        let (lo, hi) = iter.chars().size_hint();
+        assert!(lo <= text_content.chars().count() && hi == Some(demo_utf8_txt.len()));
+        
+        assert!(iter.char_indices().eq(text_content.char_indices()));
+        assert!(iter.status().is_ok());
+        
+        let mut iter = decode_utf8(b"if x1 then");
+        let ident: String = iter.chars_with_category()
+            .skip(3).take_while(|&(_, cat)| !cat.subset_of(SEPARATOR))
+            .map(|(c, _)| c).collect();
+        assert_eq!(ident, "x1");
+        
+        let mut iter = decode_utf8("abc\u{3B1}\u{3B2}".as_bytes());
+        let scripts: Vec<_> = iter.chars_with_script().map(|(_, scr)| scr).collect();
+        assert_eq!(scripts, [Script::Latin, Script::Latin, Script::Latin,
+                             Script::Greek, Script::Greek]);
+    }
+    
+    #[test]
+    fn iterators_are_fused_at_errors() {
+        let mut iter = decode_utf8(b"ab\xFFcd");
+        
+        let mut chars = iter.chars();
+        assert_eq!(chars.by_ref().collect::<String>(), "ab");
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.size_hint(), (0, Some(0)));
+        assert_eq!(iter.status().unwrap_err().offset(), 2);
+        assert_eq!(iter.chars().collect::<String>(), "cd");
+        
+        let mut iter = decode_utf8(b"ab\xFFcd");
+        iter.set_error_mode(ErrorMode::Replace);
+        assert_eq!(iter.chars().size_hint(), (2, Some(5)));
+        assert_eq!(iter.chars().collect::<String>(), "ab\u{FFFD}cd");
+    }
+    
+    #[test]
+    fn decodes_backwards() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let text_content = from_utf8(demo_utf8_txt).unwrap();
+        
+        let mut iter = decode_utf8(demo_utf8_txt);
+        assert!(iter.chars().rev().eq(text_content.chars().rev()));
+        
+        let mut iter = decode_utf8(demo_utf8_txt);
+        assert!(iter.char_indices().rev().eq(text_content.char_indices().rev()));
+        
+        let mut iter = decode_utf8("x\u{3B1}\u{65E5}\u{1F600}1".as_bytes());
+        assert_eq!(iter.prev_char_and_category(), Some(('1', DECIMAL_NUMBER)));
+        assert_eq!(iter.prev_char_and_script(), Some(('\u{1F600}', Script::Common)));
+        assert_eq!(iter.prev_char_and_script(), Some(('\u{65E5}', Script::Han)));
+        
+        let mut chars = iter.chars();
+        assert_eq!(chars.next(), Some('x'));
+        assert_eq!(chars.next_back(), Some('\u{3B1}'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next_back(), None);
+    }
+    
+    #[test]
+    fn backward_errors_match_forward_errors() {
+        fn decode(bytes: &[u8], backwards: bool) -> Vec<Result<char, (usize, Utf8ErrorKind)>> {
+            let mut iter = decode_utf8(bytes);
+            iter.set_error_mode(ErrorMode::Replace);
+            
+            let mut items = Vec::new();
+            loop {
+                let c = if backwards { iter.prev_char() } else { iter.next_char() };
+                match c {
+                    Some('\u{FFFD}') => {
+                        let err = iter.status().unwrap_err();
+                        items.push(Err((err.offset(), err.kind())));
+                    },
+                    Some(c) => items.push(Ok(c)),
+                    None => break,
+                }
+            }
+            
+            if backwards { items.reverse(); }
+            items
+        }
+        
+        let inputs: [&[u8]; 7] = [
+            &[0x61, 0xF1, 0x80, 0x80, 0xE1, 0x80, 0xC2, 0x62, 0x80, 0x63, 0x80, 0xBF, 0x64],
+            &[0xC0, 0xAF, 0xE0, 0x80, 0xBF, 0xF0, 0x81, 0x82, 0x41],
+            &[0xED, 0xA0, 0x80, 0xED, 0xBF, 0xBF, 0xED, 0xAF, 0x41],
+            &[0xF4, 0x91, 0x92, 0x93, 0xFF, 0x41, 0x80, 0xBF, 0x42],
+            &[0xE1, 0x80, 0xE2, 0xF0, 0x91, 0x92, 0xF1, 0xBF, 0x41],
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0xE2, 0x82, 0xAC, 0x80, 0xF0, 0x9F],
+            &[0xF0, 0x9F, 0x98, 0x80, 0x80, 0xE2, 0x82],
+        ];
+        
+        for bytes in inputs.iter() {
+            assert_eq!(decode(bytes, true), decode(bytes, false));
+        }
+    }
+    
+    #[test]
+    fn scans_ascii_runs() {
+        use ascii::ascii_prefix_len;
+        
+        let mut bytes = [b'a'; 100];
+        assert_eq!(ascii_prefix_len(&bytes), 100);
+        for i in 0..bytes.len() {
+            bytes[i] = 0xC3;
+            for offset in 0..i+1 {
+                assert_eq!(ascii_prefix_len(&bytes[offset..]), i - offset);
+            }
+            bytes[i] = b'a';
+        }
+        
+        let mut iter = decode_utf8("let x = \u{3B1};".as_bytes());
+        assert_eq!(iter.take_ascii(), "let x = ");
+        assert_eq!(iter.take_ascii(), "");
+        assert_eq!(iter.next_char(), Some('\u{3B1}'));
+        assert_eq!(iter.take_ascii(), ";");
+        assert!(iter.next_char().is_none());
+        
+        // Counting skips ASCII runs, but must agree with iterating.
+        let text = "let x = \u{3B1}; ".repeat(10);
+        for variant in [Utf8Variant::Standard, Utf8Variant::Cesu8].iter() {
+            let mut bytes = text.clone().into_bytes();
+            bytes.extend_from_slice(b"\xC3 \xED\xA0\x80\xC0\x80 abc");
+            for &mode in [ErrorMode::Report, ErrorMode::Replace].iter() {
+                let mut a = decode_utf8(&bytes);
+                let mut b = decode_utf8(&bytes);
+                a.set_variant(*variant); a.set_error_mode(mode);
+                b.set_variant(*variant); b.set_error_mode(mode);
+                assert_eq!(a.chars().count(), b.chars().fold(0, |n, _| n + 1));
+                assert_eq!(a.status(), b.status());
+                assert_eq!(a.char_indices().count(), b.char_indices().fold(0, |n, _| n + 1));
+            }
+        }
+    }
+    
+    #[test]
+    fn validates_like_decoder() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        assert!(validate(demo_utf8_txt).is_ok());
+        
+        for i in (0..demo_utf8_txt.len()).step_by(97) {
+            let mut bytes = demo_utf8_txt.to_vec();
+            bytes[i] ^= 0xC0;
+            
+            let mut iter = decode_utf8(&bytes);
+            while iter.next_char().is_some() {}
+            assert_eq!(validate(&bytes), iter.status());
+            
+            let expected = from_utf8(&bytes).map(|_| ()).map_err(|e| e.valid_up_to());
+            assert_eq!(validate(&bytes).map_err(|e| e.offset()), expected);
+        }
+    }
+    
+    #[test]
+    fn decodes_properties_together() {
+        let all_chars: String = (0..0x110000).filter_map(from_u32).collect();
+        let bytes = all_chars.as_bytes();
+        
+        let mut cats = decode_utf8(bytes);
+        let mut scripts = decode_utf8(bytes);
+        let mut props = decode_utf8(bytes);
+        
+        let separate = cats.chars_with_category().zip(scripts.chars_with_script())
+            .map(|((c, cat), (_, scr))| (c, cat, scr));
+        assert!(props.chars_with_properties().eq(separate));
+        
+        let mut cats = decode_utf8(bytes);
+        let mut scripts = decode_utf8(bytes);
+        let mut props = decode_utf8(bytes);
+        
+        let separate = cats.chars_with_category().rev().zip(scripts.chars_with_script().rev())
+            .map(|((c, cat), (_, scr))| (c, cat, scr));
+        assert!(props.chars_with_properties().rev().eq(separate));
+    }
+    
+    #[test]
+    fn decodes_utf16() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let text_content = from_utf8(demo_utf8_txt).unwrap();
+        
+        let units: Vec<u16> = text_content.encode_utf16().collect();
+        let le: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes().to_vec()).collect();
+        let be: Vec<u8> = units.iter().flat_map(|u| u.to_be_bytes().to_vec()).collect();
+        
+        let mut expected = decode_utf8(demo_utf8_txt);
+        let mut iter = decode_utf16le(&le);
+        while let Some(got) = iter.next_char_and_category() {
+            assert_eq!(Some(got), expected.next_char_and_category());
+        }
+        assert!(expected.next_char().is_none());
+        
+        let mut expected = decode_utf8(demo_utf8_txt);
+        let mut iter = decode_utf16be(&be);
+        let start = iter.mark();
+        while let Some(got) = iter.next_char_and_script() {
+            assert_eq!(Some(got), expected.next_char_and_script());
+        }
+        assert!(expected.next_char().is_none());
+        assert_eq!(iter.get_marked_bytes(start), &be[..]);
+        assert!(iter.status().is_ok());
+    }
+    
+    #[test]
+    fn detects_utf16_errors() {
+        use self::Utf16ErrorKind::*;
+        
+        let bytes = [0x41, 0x00, 0x00, 0xDC, 0x00, 0xD8, 0x42, 0x00, 0x3D, 0xD8, 0x00, 0xDE,
+                     0x00, 0xD8];
+        let mut iter = decode_utf16le(&bytes);
+        
+        assert_eq!(iter.next_char(), Some('A'));
+        assert!(iter.next_char().is_none());
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.error_len()), (UnpairedSurrogate, 2, Some(2)));
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.status().unwrap_err().offset(), 4);
+        assert_eq!(iter.next_char(), Some('B'));
+        assert_eq!(iter.next_char(), Some('\u{1F600}'));
+        assert!(iter.next_char().is_none());
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.error_len()), (UnexpectedEndOfBuffer, 12, None));
+        
+        let mut iter = decode_utf16be(&[0x00, 0x41, 0xD8]);
+        iter.set_error_mode(ErrorMode::Replace);
+        assert_eq!(iter.next_char_and_script(), Some(('A', Script::Latin)));
+        assert_eq!(iter.next_char_and_category(), Some(('\u{FFFD}', OTHER_SYMBOL)));
+        assert_eq!(iter.status().unwrap_err().kind(), UnexpectedEndOfBuffer);
+        assert!(iter.next_char().is_none());
+    }
+    
+    #[test]
+    fn decodes_utf32() {
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let text_content = from_utf8(demo_utf8_txt).unwrap();
+        
+        let le: Vec<u8> = text_content.chars().flat_map(|c| (c as u32).to_le_bytes().to_vec()).collect();
+        let mut expected = decode_utf8(demo_utf8_txt);
+        let mut iter = decode_utf32le(&le);
+        while let Some(got) = iter.next_char_and_category() {
+            assert_eq!(Some(got), expected.next_char_and_category());
+        }
+        assert!(expected.next_char().is_none());
+        assert!(iter.status().is_ok());
+        
+        let bytes = [0, 0, 0, 0x41, 0, 0, 0xD8, 0, 0, 0x11, 0, 0, 0, 0x01, 0xF6, 0x00, 0];
+        let mut iter = decode_utf32be(&bytes);
+        iter.set_error_mode(ErrorMode::Replace);
+        
+        let mark = iter.mark();
+        assert_eq!(iter.next_char_and_script(), Some(('A', Script::Latin)));
+        assert_eq!(iter.get_marked_bytes(mark), &bytes[..4]);
+        assert_eq!(iter.next_char(), Some('\u{FFFD}'));
+        assert_eq!(iter.status().unwrap_err().kind(), Utf32ErrorKind::SurrogateCharacter);
+        assert_eq!(iter.next_char(), Some('\u{FFFD}'));
+        assert_eq!(iter.status().unwrap_err().kind(), Utf32ErrorKind::OutOfCharacterRange);
+        assert_eq!(iter.next_char(), Some('\u{1F600}'));
+        assert_eq!(iter.next_char(), Some('\u{FFFD}'));
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.error_len()), (Utf32ErrorKind::UnexpectedEndOfBuffer, 16, None));
+        assert!(iter.next_char().is_none());
+    }
+    
+    #[test]
+    fn decodes_single_byte_encodings() {
+        let bytes = b"caf\xE9 \x80\x81\x9F";
+        
+        let latin1: String = {
+            let mut iter = decode_latin1(bytes);
+            let mut s = String::new();
+            while let Some(c) = iter.next_char() { s.push(c); }
+            s
+        };
+        assert_eq!(latin1, "caf\u{E9} \u{80}\u{81}\u{9F}");
+        
+        let mut iter = decode_windows1252(bytes);
+        let mark = iter.mark();
+        assert_eq!(iter.next_char_and_script(), Some(('c', Script::Latin)));
+        assert_eq!(iter.next_char_and_category(), Some(('a', LOWERCASE_LETTER)));
+        assert_eq!(iter.next_char(), Some('f'));
+        assert_eq!(iter.next_char_and_category(), Some(('\u{E9}', LOWERCASE_LETTER)));
+        assert_eq!(iter.next_char_and_category(), Some((' ', SPACE_SEPARATOR)));
+        assert_eq!(iter.next_char_and_category(), Some(('\u{20AC}', CURRENCY_SYMBOL)));
+        assert_eq!(iter.next_char_and_category(), Some(('\u{81}', CONTROL)));
+        assert_eq!(iter.next_char_and_category(), Some(('\u{178}', UPPERCASE_LETTER)));
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.get_marked_bytes(mark), &bytes[..]);
+    }
+    
+    #[test]
+    fn decoders_share_a_trait() {
+        fn words<D: CharDecoder>(mut decoder: D) -> Result<Vec<String>, D::Error> {
+            let mut words = vec![];
+            let mut word = String::new();
+            while let Some((c, cat)) = decoder.next_char_and_category() {
+                if cat.subset_of(LETTER) {
+                    word.push(c);
+                } else if !word.is_empty() {
+                    words.push(word.split_off(0));
+                }
+            }
+            decoder.status().map(|_| words)
+        }
+        
+        let text = "Gr\u{FC}\u{DF} dich, Welt!";
+        let utf16: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_le_bytes().to_vec()).collect();
+        let utf32: Vec<u8> = text.chars().flat_map(|c| (c as u32).to_be_bytes().to_vec()).collect();
+        let latin1: Vec<u8> = text.chars().map(|c| c as u8).collect();
+        
+        let expected = vec!["Gr\u{FC}\u{DF}".to_string(), "dich".to_string(), "Welt".to_string()];
+        assert_eq!(words(decode_utf8(text.as_bytes())).unwrap(), expected);
+        assert_eq!(words(decode_utf16le(&utf16)).unwrap(), expected);
+        assert_eq!(words(decode_utf32be(&utf32)).unwrap(), expected);
+        assert_eq!(words(decode_latin1(&latin1)).unwrap(), expected);
+        assert_eq!(words(decode_utf8(b"ab\xFFcd")).unwrap_err().offset(), 2);
+    }
+    
+    #[test]
+    fn decodes_wtf8() {
+        let bytes = b"a\xED\xA0\x80b\xED\xBF\xBF";
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Wtf8);
+        assert_eq!(iter.next_code_point_and_category(), Some((0x61, LOWERCASE_LETTER)));
+        assert_eq!(iter.next_code_point_and_category(), Some((0xD800, SURROGATE)));
+        assert_eq!(iter.next_code_point_and_script(), Some((0x62, Script::Latin)));
+        assert_eq!(iter.next_code_point_and_script(), Some((0xDFFF, Script::Unknown)));
+        assert!(iter.next_code_point().is_none());
+        assert!(iter.status().is_ok());
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Wtf8);
+        let mark = iter.mark();
+        assert_eq!(iter.next_char(), Some('a'));
+        assert!(iter.next_char().is_none());
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.bytes()), (Utf8ErrorKind::SurrogateCharacter, 1, &bytes[1..4]));
+        assert_eq!(iter.next_char(), Some('b'));
+        assert_eq!(iter.try_get_marked_string(mark).unwrap_err().offset(), 1);
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.status().unwrap_err().offset(), 5);
+        
+        let mut iter = decode_utf8(bytes);
+        assert_eq!(iter.next_code_point(), Some(0x61));
+        assert!(iter.next_code_point().is_none());
+        assert_eq!(iter.status().unwrap_err().bytes(), &bytes[1..2]);
+    }
+    
+    #[test]
+    fn rejects_surrogate_pairs_in_wtf8() {
+        let bytes = b"\xED\xA0\xBD\xED\xB8\x80\xF0\x9F\x98\x80";
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Wtf8);
+        assert!(iter.next_code_point().is_none());
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.bytes()), (Utf8ErrorKind::SurrogateCharacter, 0, &bytes[..6]));
+        assert_eq!(iter.next_code_point(), Some(0x1F600));
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Wtf8);
+        iter.set_error_mode(ErrorMode::Replace);
+        assert_eq!(iter.next_code_point(), Some(0xFFFD));
+        assert_eq!(iter.next_code_point(), Some(0x1F600));
+        assert_eq!(iter.next_code_point(), None);
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Wtf8);
+        assert_eq!(iter.prev_char(), Some('\u{1F600}'));
+        assert!(iter.prev_char().is_none());
+        assert_eq!(iter.status().unwrap_err().bytes(), &bytes[..6]);
+        
+        // Reversed, they are two lone surrogates.
+        let mut iter = decode_utf8(b"\xED\xB8\x80\xED\xA0\xBD");
+        iter.set_variant(Utf8Variant::Wtf8);
+        assert_eq!(iter.next_code_point(), Some(0xDE00));
+        assert_eq!(iter.next_code_point(), Some(0xD83D));
+        assert!(iter.status().is_ok());
+    }
+    
+    #[test]
+    fn decodes_cesu8_and_modified_utf8() {
+        let bytes = b"\xED\xA0\xBD\xED\xB8\x80\xC0\x80\xED\xA0\xBDx\xF0\x9F\x98\x80";
+        
+        let decode = |variant: Utf8Variant| {
+            let mut iter = decode_utf8(bytes);
+            iter.set_variant(variant);
+            iter.set_error_mode(ErrorMode::Replace);
+            
+            let mut items = vec![];
+            while let Some((c, cat)) = iter.next_char_and_category() {
+                items.push(match c {
+                    '\u{FFFD}' => Err(iter.status().unwrap_err().kind()),
+                    _ => Ok((c, cat)),
+                });
+            }
+            items
+        };
+        
+        use self::Utf8ErrorKind::*;
+        let cesu8 = decode(Utf8Variant::Cesu8);
+        assert_eq!(cesu8[0], Ok(('\u{1F600}', OTHER_SYMBOL)));
+        assert_eq!(&cesu8[1..5], &[Err(NotALeadingByte), Err(NotALeadingByte),
+            Err(SurrogateCharacter), Ok(('x', LOWERCASE_LETTER))]);
+        assert_eq!(cesu8[5], Err(NotALeadingByte));
+        
+        let mutf8 = decode(Utf8Variant::ModifiedUtf8);
+        assert_eq!(&mutf8[..4], &[Ok(('\u{1F600}', OTHER_SYMBOL)), Ok(('\0', CONTROL)),
+            Err(SurrogateCharacter), Ok(('x', LOWERCASE_LETTER))]);
+        
+        let mut iter = decode_utf8(b"\xC0\x81\xC0\x80");
+        iter.set_variant(Utf8Variant::ModifiedUtf8);
+        assert!(iter.next_char().is_none());
+        assert_eq!(iter.status().unwrap_err().kind(), OverlongEncoding);
+        assert_eq!(iter.prev_char_and_script(), Some(('\0', Script::Common)));
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.status().unwrap_err().offset(), 1);
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_variant(Utf8Variant::Cesu8);
+        assert!(iter.prev_char().is_none());
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.prev_char(), Some('x'));
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.status().unwrap_err().kind(), SurrogateCharacter);
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.prev_char(), None);
+        assert_eq!(iter.prev_char_and_category(), Some(('\u{1F600}', OTHER_SYMBOL)));
+    }
+    
+    #[test]
+    fn escapes_invalid_bytes_losslessly() {
+        fn round_trip(bytes: &[u8], variant: Utf8Variant) -> Vec<u8> {
+            let mut iter = decode_utf8(bytes);
+            iter.set_error_mode(ErrorMode::Escape);
+            iter.set_variant(variant);
+            
+            let mut buf = [0; 4];
+            let mut out = Vec::new();
+            while let Some(cp) = iter.next_code_point() {
+                out.extend_from_slice(encode_escaped(cp, &mut buf).unwrap());
+            }
+            out
+        }
+        
+        let mut iter = decode_utf8(b"a\xF0\x9F\x98b\xED\xB2\x80");
+        iter.set_error_mode(ErrorMode::Escape);
+        assert_eq!(iter.next_code_point_and_category(), Some((0x61, LOWERCASE_LETTER)));
+        assert_eq!(iter.next_code_point_and_category(), Some((0xDCF0, SURROGATE)));
+        let err = iter.status().unwrap_err();
+        assert_eq!((err.kind(), err.offset(), err.error_len()), (Utf8ErrorKind::NotAContinuationByte, 1, Some(3)));
+        assert_eq!(iter.next_code_point_and_script(), Some((0xDC9F, Script::Unknown)));
+        assert_eq!(iter.next_code_point(), Some(0xDC98));
+        assert_eq!(iter.next_code_point(), Some(0x62));
+        assert_eq!(iter.next_code_point(), Some(0xDCED));
+        assert_eq!(iter.status().unwrap_err().kind(), Utf8ErrorKind::SurrogateCharacter);
+        assert_eq!(iter.next_code_point(), Some(0xDCB2));
+        assert_eq!(iter.next_code_point(), Some(0xDC80));
+        assert_eq!(iter.next_code_point(), None);
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        assert_eq!(round_trip(demo_utf8_txt, Utf8Variant::Standard), &demo_utf8_txt[..]);
+        
+        let mut x = 0x2545F4914F6CDD1Du64;
+        for _ in 0..2000 {
+            let bytes: Vec<u8> = (0..(x % 12)).map(|_| {
+                x ^= x << 13; x ^= x >> 7; x ^= x << 17;
+                [0x41, 0x80, 0xA0, 0xB2, 0xBF, 0xC0, 0xC2, 0xE0, 0xED, 0xF0, 0xF4, 0xFF][(x % 12) as usize]
+            }).collect();
+            x ^= x << 13; x ^= x >> 7; x ^= x << 17;
+            
+            assert_eq!(round_trip(&bytes, Utf8Variant::Standard), bytes);
+            assert_eq!(round_trip(&bytes, Utf8Variant::Wtf8), bytes);
+        }
+    }
+    
+    #[test]
+    fn splits_valid_and_invalid_chunks() {
+        use self::Utf8ErrorKind::*;
+        
+        let bytes = b"ab\xF0\x9F\x98cd\xED\xA0\x80\xE2\x82\xAC\xC2";
+        let chunks: Vec<_> = utf8_chunks(bytes)
+            .map(|c| (c.valid(), c.invalid(), c.error().map(|e| (e.kind(), e.offset()))))
+            .collect();
+        
+        assert_eq!(chunks, vec![
+            ("ab", &b"\xF0\x9F\x98"[..], Some((NotAContinuationByte, 2))),
+            ("cd", &b"\xED"[..], Some((SurrogateCharacter, 7))),
+            ("", &b"\xA0"[..], Some((NotALeadingByte, 8))),
+            ("", &b"\x80"[..], Some((NotALeadingByte, 9))),
+            ("\u{20AC}", &b"\xC2"[..], Some((UnexpectedEndOfBuffer, 13))),
+        ]);
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let chunks: Vec<_> = utf8_chunks(demo_utf8_txt).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].valid().as_bytes(), &demo_utf8_txt[..]);
+        assert!(chunks[0].error().is_none());
+        assert_eq!(utf8_chunks(b"").next(), None);
+        
+        let inputs: [&[u8]; 3] = [
+            &[0x61, 0xF1, 0x80, 0x80, 0xE1, 0x80, 0xC2, 0x62, 0x80, 0x63, 0x80, 0xBF, 0x64],
+            &[0xC0, 0xAF, 0xE0, 0x80, 0xBF, 0xF0, 0x81, 0x82, 0x41],
+            &[0xF4, 0x91, 0x92, 0x93, 0xFF, 0x41, 0x80, 0xBF, 0x42],
+        ];
+        for bytes in inputs.iter() {
+            let mut lossy = String::new();
+            for chunk in utf8_chunks(bytes) {
+                lossy.push_str(chunk.valid());
+                if !chunk.invalid().is_empty() { lossy.push('\u{FFFD}'); }
+            }
+            assert_eq!(lossy, String::from_utf8_lossy(bytes));
+        }
+    }
+    
+    #[test]
+    #[cfg(feature = "std")]
+    fn converts_lossily_with_report() {
+        use std::borrow::Cow;
+        use self::Utf8ErrorKind::*;
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let (text, report) = decode_utf8_lossy(demo_utf8_txt);
+        assert!(match text { Cow::Borrowed(s) => s.as_bytes() == &demo_utf8_txt[..], _ => false });
+        assert_eq!(report, Utf8Report::default());
+        assert_eq!(decode_utf8_lossy(b"").0, "");
+        
+        let bytes = b"a\x80b\xC0\xAF\xED\xA0\x80\xF4\x90\xE2\x82\xACz\xE2\x82";
+        let (text, report) = decode_utf8_lossy(bytes);
+        assert_eq!(text, String::from_utf8_lossy(bytes));
+        assert_eq!(report.total(), 9);
+        assert_eq!(report.count(NotALeadingByte), 6);
+        assert_eq!(report.count(OverlongEncoding), 0);
+        assert_eq!(report.count(SurrogateCharacter), 1);
+        assert_eq!(report.count(OutOfCharacterRange), 1);
+        assert_eq!(report.count(UnexpectedEndOfBuffer), 1);
+        assert_eq!(report.count(NotAContinuationByte), 0);
+        assert_eq!(report.error_offsets(), &[1, 3, 4, 5, 6, 7, 8, 9]);
+    }
+    
+    #[test]
+    fn collects_every_error() {
+        use self::Utf8ErrorKind::*;
+        
+        let bytes = b"a\x80b\xF0\x9F\x98c\xE2\x82";
+        let errors: Vec<_> = utf8_errors(bytes)
+            .map(|e| (e.offset(), e.error_len(), e.kind()))
+            .collect();
+        assert_eq!(errors, vec![
+            (1, Some(1), NotALeadingByte),
+            (3, Some(3), NotAContinuationByte),
+            (7, None, UnexpectedEndOfBuffer),
+        ]);
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        assert_eq!(utf8_errors(demo_utf8_txt).next(), None);
+        
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(validate_all(bytes, None).len(), 3);
+            assert_eq!(validate_all(bytes, Some(2)), utf8_errors(bytes).take(2).collect::<Vec<_>>());
+            assert!(validate_all(demo_utf8_txt, Some(10)).is_empty());
+        }
+    }
+    
+    #[test]
+    fn peeks_without_consuming() {
+        let mut iter = decode_utf8(b".5..\xFFx");
+        assert_eq!(iter.peek_char(), Some('.'));
+        assert_eq!(iter.peek_nth(1), Some('5'));
+        assert_eq!(iter.peek_char_and_category(), Some(('.', OTHER_PUNCTUATION)));
+        assert_eq!(iter.peek_nth(4), None);
+        assert_eq!(iter.peek_nth(5), Some('x'));
+        assert_eq!(iter.peek_nth(6), None);
+        assert!(iter.status().is_ok());
+        
+        assert_eq!(iter.next_char(), Some('.'));
+        assert_eq!(iter.next_char(), Some('5'));
+        assert_eq!(iter.peek_nth(1), Some('.'));
+        assert_eq!(iter.prev_char(), Some('x'));
+        assert_eq!(iter.peek_nth(2), None);
+        assert!(iter.status().is_ok());
+        assert_eq!(iter.peek_nth(3), None);
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let mut iter = decode_utf8(demo_utf8_txt);
+        loop {
+            let mut ahead = iter.clone();
+            for n in 0..6 {
+                assert_eq!(iter.peek_nth_and_category(5 - n), {
+                    let mut ahead = ahead.clone();
+                    for _ in 0..5 - n { ahead.next_char(); }
+                    ahead.next_char_and_category()
+                });
+            }
+            assert_eq!(iter.peek_char(), ahead.next_char());
+            if iter.next_char().is_none() { break; }
+        }
+        
+        // Broken sequences inside the window, with and without the end moved
+        // back, peek exactly like decoding them does.
+        let inputs: [&[u8]; 6] = [
+            b"\xF3\xC0", b"\xC2\x80\xF4\xC0", b"a\xE0\x80\xF4\x90\x80\x80z",
+            b"\xED\xA0\x80\xF0\x9F\x98", b"\xC2\xC2\xC2\x80", b"\xF4\xB2\x98\xE0\x90",
+        ];
+        for input in inputs.iter() {
+            for &mode in &[ErrorMode::Report, ErrorMode::Replace] {
+                for back in 0..2 {
+                    let mut iter = decode_utf8(input);
+                    iter.set_error_mode(mode);
+                    for _ in 0..back { iter.prev_char(); }
+                    for n in 0..6 {
+                        let mut ahead = iter.clone();
+                        for _ in 0..n { ahead.next_char(); }
+                        assert_eq!(iter.peek_nth(n), ahead.clone().next_char());
+                        assert_eq!(iter.peek_nth_and_category(n), ahead.next_char_and_category());
+                    }
+                    
+                    let status = iter.status();
+                    let peeked = iter.peek_char_and_category();
+                    assert_eq!(iter.status(), status);
+                    assert_eq!(iter.next_char_and_category(), peeked);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn resets_to_marks() {
+        let mut iter = decode_utf8(b"ab\xFFcd");
+        let start = iter.mark();
+        
+        assert_eq!(iter.next_char(), Some('a'));
+        assert_eq!(iter.next_char(), Some('b'));
+        let before_err = iter.mark();
+        assert_eq!(iter.next_char(), None);
+        assert_eq!(iter.next_char(), Some('c'));
+        assert!(iter.try_get_marked_string(start).is_err());
+        let after_err = iter.mark();
+        
+        iter.reset_to(start);
+        assert!(iter.status().is_ok());
+        assert_eq!(iter.peek_char(), Some('a'));
+        assert_eq!(iter.try_get_marked_string(start).unwrap(), "");
+        
+        iter.reset_to(before_err);
+        assert!(iter.status().is_ok());
+        assert_eq!(iter.try_get_marked_string(start).unwrap(), "ab");
+        
+        iter.reset_to(after_err);
+        assert_eq!(iter.status().unwrap_err().offset(), 2);
+        assert_eq!(iter.try_get_marked_string(before_err).unwrap_err().offset(), 2);
+        assert_eq!(iter.next_char(), Some('d'));
+        assert_eq!(iter.try_get_marked_string(after_err).unwrap(), "d");
+        assert_eq!(iter.next_char(), None);
+        assert!(iter.status().is_err());
+        
+        let mut iter = decode_utf8(b"\xF0\x9F\x98x");
+        iter.set_error_mode(ErrorMode::Escape);
+        assert_eq!(iter.next_code_point(), Some(0xDCF0));
+        let escaping = iter.mark();
+        assert_eq!(iter.next_code_point(), Some(0xDC9F));
+        assert_eq!(iter.next_code_point(), Some(0xDC98));
+        iter.reset_to(escaping);
+        assert_eq!(iter.next_code_point(), Some(0xDC9F));
+        assert_eq!(iter.next_code_point(), Some(0xDC98));
+        assert_eq!(iter.next_code_point(), Some(0x78));
+    }
+    
+    #[test]
+    fn scans_while_category_or_script() {
+        let mut iter = decode_utf8(b"foo_bar42 \xCE\xB1\xCE\xB2\xCE\xB3\xF0\x9F\x98\x80\xCE\xB4\xFF");
+        assert_eq!(iter.take_while_category(LETTER), "foo");
+        iter.skip_while_category(CONNECTOR_PUNCTUATION);
+        assert_eq!(iter.take_while_category(LETTER | DECIMAL_NUMBER), "bar42");
+        assert_eq!(iter.take_while_category(LETTER), "");
+        iter.skip_while_script(Script::Common);
+        assert_eq!(iter.take_while_script(Script::Greek), "\u{3B1}\u{3B2}\u{3B3}");
+        assert_eq!(iter.take_while_category(OTHER_SYMBOL | LETTER), "\u{1F600}\u{3B4}");
+        assert!(iter.status().is_ok());
+        assert_eq!(iter.next_char(), None);
+        assert_eq!(iter.status().unwrap_err().offset(), 22);
+        
+        let mut iter = decode_utf8(b"ab\xED\xA0\xBD\xED\xB8\x80\xC0\x80cd");
+        iter.set_variant(Utf8Variant::ModifiedUtf8);
+        assert_eq!(iter.take_while_category(LETTER | OTHER_SYMBOL), "ab");
+        assert_eq!(iter.next_char(), Some('\u{1F600}'));
+        assert_eq!(iter.take_while_category(!0), "");
+        assert_eq!(iter.next_char(), Some('\0'));
+        assert_eq!(iter.take_while_script(Script::Latin), "cd");
+        
+        let demo_utf8_txt = include_bytes!("../tst-dat/demo-utf8.txt");
+        let mut iter = decode_utf8(demo_utf8_txt);
+        let mut reference = iter.clone();
+        while iter.peek_char().is_some() {
+            let (_, cat) = reference.clone().next_char_and_category().unwrap();
+            let (_, scr) = reference.clone().next_char_and_script().unwrap();
+            
+            let run = if cat == SPACE_SEPARATOR { iter.take_while_script(scr) } else { iter.take_while_category(cat) };
+            assert!(!run.is_empty());
+            for c in run.chars() {
+                let (expected, expected_cat) = reference.next_char_and_category().unwrap();
+                assert_eq!(c, expected);
+                assert!(cat == SPACE_SEPARATOR || expected_cat == cat);
+            }
+            
+            let mut next = reference.clone();
+            assert_eq!(iter.peek_char(), next.next_char());
+        }
+    }
+    
+    #[test]
+    fn tracks_lines_and_columns() {
+        let bytes = b"ab\r\ncd\re\n\nf\xC2\x85g\xE2\x80\xA8h\xF0\x9F\x98\x80i\xFF\xE2\x82j";
+        let line_column = |d: &Utf8Decoder| {
+            let pos = d.line_column().unwrap();
+            (pos.line(), pos.column())
+        };
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_error_mode(ErrorMode::Replace);
+        let start = iter.mark();
+        iter.track_lines(ColumnUnit::Chars);
+        assert_eq!(line_column(&iter), (0, 0));
+        assert_eq!(iter.next_char(), Some('a'));
+        assert_eq!(line_column(&iter), (0, 1));
+        iter.skip_while_category(LETTER);
+        assert_eq!(line_column(&iter), (0, 2));
+        assert_eq!(iter.next_char(), Some('\r'));
+        assert_eq!(line_column(&iter), (1, 0));
+        assert_eq!(iter.take_ascii(), "\ncd\re\n\nf");
+        assert_eq!(line_column(&iter), (4, 1));
+        
+        iter.reset_to(start);
+        assert!(iter.line_column().is_none());
+        iter.track_lines(ColumnUnit::Chars);
+        for _ in 0..3 { iter.next_char(); }
+        assert_eq!(line_column(&iter), (1, 0));
+        assert_eq!(iter.next_char(), Some('\n'));
+        assert_eq!(line_column(&iter), (1, 0));
+        iter.skip_while_category(LETTER);
+        assert_eq!(line_column(&iter), (1, 2));
+        let mark = iter.mark();
+        
+        let mut positions = vec![];
+        while iter.next_char().is_some() { positions.push(line_column(&iter)); }
+        assert_eq!(positions, vec![(2, 0), (2, 1), (3, 0), (4, 0), (4, 1), (5, 0), (5, 1),
+            (6, 0), (6, 1), (6, 2), (6, 3), (6, 4), (6, 5), (6, 6)]);
+        
+        iter.reset_to(mark);
+        assert_eq!(mark.line_column().map(|pos| (pos.line(), pos.column())), Some((1, 2)));
+        assert_eq!(line_column(&iter), (1, 2));
+        while iter.next_char().is_some() {}
+        assert_eq!(line_column(&iter), (6, 6));
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_error_mode(ErrorMode::Replace);
+        iter.track_lines(ColumnUnit::Utf16Units);
+        while iter.next_code_point().is_some() {}
+        assert_eq!(line_column(&iter), (6, 7));
+        
+        let mut iter = decode_utf8(bytes);
+        iter.set_error_mode(ErrorMode::Replace);
+        iter.track_lines(ColumnUnit::Bytes);
+        assert_eq!(iter.chars().count(), 20);
+        assert_eq!(line_column(&iter), (6, 10));
+        
+        let mut iter = decode_utf8(b"a\xED\xA0\xBD\xED\xB8\x80b\nc");
+        iter.set_variant(Utf8Variant::Cesu8);
+        iter.track_lines(ColumnUnit::Chars);
+        assert_eq!(iter.peek_nth(2), Some('b'));
+        assert_eq!(line_column(&iter), (0, 0));
+        iter.skip_while_category(!0);
+        iter.next_char();
+        assert_eq!(line_column(&iter), (0, 2));
+        iter.skip_while_category(!0);
+        assert_eq!(line_column(&iter), (1, 1));
+        assert!(decode_utf8(b"").line_column().is_none());
+    }
+    
+    #[test]
+    fn looks_up_properties_by_char() {
+        const A: (GeneralCategory, Script) = (general_category('A'), script('A'));
+        assert_eq!(A, (UPPERCASE_LETTER, Script::Latin));
+        
+        let mut buf = [0; 4];
+        for c in (0..0x110000).filter_map(from_u32) {
+            let (_, cat, scr) = decode_utf8(c.encode_utf8(&mut buf).as_bytes())
+                .next_char_and_properties().unwrap();
+            assert_eq!((general_category(c), script(c)), (cat, scr));
+        }
+    }
+    
+    #[test]
+    fn looks_up_script_extensions() {
+        const DANDA: ScriptSet = script_extensions('\u{964}');
+        assert!(DANDA.contains(Script::Devanagari) && DANDA.contains(Script::Bengali));
+        assert!(!DANDA.contains(Script::Latin));
+        
+        let mut d = decode_utf8("a\u{30FC}\u{3042}\u{FF}".as_bytes());
+        let latin = ScriptSet::single(Script::Latin);
+        assert_eq!(d.next_char_and_script_extensions(), Some(('a', latin)));
+        let (_, prolonged) = d.next_char_and_script_extensions().unwrap();
+        assert_eq!(prolonged.iter().collect::<Vec<_>>(), [Script::Hiragana, Script::Katakana]);
+        let (_, hiragana) = d.next_char_and_script_extensions().unwrap();
+        assert_eq!(prolonged.intersection(hiragana), ScriptSet::single(Script::Hiragana));
+        assert_eq!(d.next_char_and_script_extensions(), Some(('\u{FF}', latin)));
+        assert_eq!(d.next_char_and_script_extensions(), None);
+        assert!(latin.intersection(hiragana).is_empty());
+        
+        // Only characters without a script of their own are narrowed down.
+        for c in (0..0x110000).filter_map(from_u32) {
+            let (set, sc) = (script_extensions(c), script(c));
+            assert!(!set.is_empty());
+            assert!(set.contains(sc) || matches!(sc, Script::Common | Script::Inherited));
+        }
+        
+        let all: String = (0..0x110000).filter_map(from_u32).collect();
+        let mut d = decode_utf8(all.as_bytes());
+        for c in all.chars() {
+            assert_eq!(d.next_char_and_script_extensions(), Some((c, script_extensions(c))));
+        }
+    }
+    
+    fn ucd_ranges(data: &str) -> Vec<(u32, u32, &str)> {
+        data.lines().filter_map(|l| {
+            let l = l.split('#').next().unwrap().trim();
+            if l.is_empty() { return None; }
+            
+            let mut fields = l.split(';').map(str::trim);
+            let (range, value) = (fields.next().unwrap(), fields.next().unwrap());
+            let mut range = range.split("..").map(|x| u32::from_str_radix(x, 16).unwrap());
+            let start = range.next().unwrap();
+            Some((start, range.next().unwrap_or(start), value))
+        }).collect()
+    }
+    
+    // The tables come from the data files in `gen/dat`, so comparing them with
+    // those only checks the generator. The properties std exposes are derived
+    // from the same Unicode version, independently of them.
+    #[test]
+    fn categories_agree_with_std() {
+        assert_eq!(UNICODE_VERSION, char::UNICODE_VERSION);
+        
+        for c in (0..0x110000).filter_map(from_u32) {
+            let cat = general_category(c);
+            assert_eq!(c.is_control(), cat == CONTROL);
+            assert_eq!(c.is_numeric(), cat.subset_of(NUMBER));
+            assert_eq!(cat == PRIVATE_USE, matches!(c,
+                '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'));
+            
+            // Derived properties that add a few characters to some categories.
+            if cat.subset_of(LETTER | LETTER_NUMBER) { assert!(c.is_alphabetic()); }
+            if c.is_alphabetic() { assert!(cat.subset_of(LETTER | LETTER_NUMBER | MARK | OTHER_SYMBOL)); }
+            if cat == LOWERCASE_LETTER { assert!(c.is_lowercase() && !c.is_uppercase()); }
+            if cat == UPPERCASE_LETTER { assert!(c.is_uppercase() && !c.is_lowercase()); }
+            if cat == TITLECASE_LETTER { assert!(!c.is_uppercase() && !c.is_lowercase()); }
+            if cat.subset_of(SEPARATOR) { assert!(c.is_whitespace()); }
+            if c.is_whitespace() { assert!(cat.subset_of(SEPARATOR | CONTROL)); }
+            
+            // Noncharacters are never assigned.
+            if (c as u32) & 0xFFFE == 0xFFFE || ('\u{FDD0}'..='\u{FDEF}').contains(&c) {
+                assert_eq!(cat, UNASSIGNED);
+            }
+        }
+    }
+    
+    #[test]
+    fn matches_bundled_unicode_data() {
+        const DGC: &str = include_str!("../gen/dat/DerivedGeneralCategory.txt");
+        const SCR: &str = include_str!("../gen/dat/Scripts.txt");
+        const SCX: &str = include_str!("../gen/dat/ScriptExtensions.txt");
+        const CATS: [(&str, GeneralCategory); 30] = [
+            ("Lu", UPPERCASE_LETTER), ("Ll", LOWERCASE_LETTER), ("Lt", TITLECASE_LETTER),
+            ("Lm", MODIFIER_LETTER), ("Lo", OTHER_LETTER),
+            ("Mn", NONSPACING_MARK), ("Mc", SPACING_MARK), ("Me", ENCLOSING_MARK),
+            ("Nd", DECIMAL_NUMBER), ("Nl", LETTER_NUMBER), ("No", OTHER_NUMBER),
+            ("Pc", CONNECTOR_PUNCTUATION), ("Pd", DASH_PUNCTUATION), ("Ps", OPEN_PUNCTUATION),
+            ("Pe", CLOSE_PUNCTUATION), ("Pi", INITIAL_PUNCTUATION), ("Pf", FINAL_PUNCTUATION),
+            ("Po", OTHER_PUNCTUATION),
+            ("Sm", MATH_SYMBOL), ("Sc", CURRENCY_SYMBOL), ("Sk", MODIFIER_SYMBOL),
+            ("So", OTHER_SYMBOL),
+            ("Zs", SPACE_SEPARATOR), ("Zl", LINE_SEPARATOR), ("Zp", PARAGRAPH_SEPARATOR),
+            ("Cc", CONTROL), ("Cf", FORMAT), ("Cs", SURROGATE), ("Co", PRIVATE_USE),
+            ("Cn", UNASSIGNED),
+        ];
+        
+        let mut cats = vec![UNASSIGNED; 0x110000];
+        for (start, end, value) in ucd_ranges(DGC) {
+            let cat = CATS.iter().find(|c| c.0 == value).unwrap().1;
+            for cp in start..=end { cats[cp as usize] = cat; }
+        }
+        
+        let mut scripts = vec![Script::Unknown; 0x110000];
+        for (start, end, value) in ucd_ranges(SCR) {
+            let scr = Script::from_name(value).unwrap();
+            for cp in start..=end { scripts[cp as usize] = scr; }
+        }
+        
+        let mut extensions = vec![None; 0x110000];
+        for (start, end, value) in ucd_ranges(SCX) {
+            let set = value.split(' ').map(|s| ScriptSet::single(Script::from_name(s).unwrap()))
+                .fold(ScriptSet::empty(), ScriptSet::union);
+            for cp in start..=end { extensions[cp as usize] = Some(set); }
+        }
+        
+        for c in (0..0x110000).filter_map(from_u32) {
+            let cp = c as usize;
+            assert_eq!(general_category(c), cats[cp]);
+            assert_eq!(script(c), scripts[cp]);
+            assert_eq!(script_extensions(c), extensions[cp].unwrap_or(ScriptSet::single(scripts[cp])));
+        }
+        
+        assert_eq!(UNICODE_VERSION, (17, 0, 0));
+        assert_eq!(script('\u{10FE0}'), Script::Elymaic);
+        assert_eq!(script('\u{119A0}'), Script::Nandinagari);
+        assert_eq!(script('\u{1E290}'), Script::Toto);
+        assert_eq!(script('\u{11F00}'), Script::Kawi);
+        assert_eq!(general_category('\u{1FAE9}'), OTHER_SYMBOL);
+    }
+    
+    #[test]
+    fn looks_up_property_value_names() {
+        assert_eq!(Script::from_name("Old_Italic"), Some(Script::OldItalic));
+        assert_eq!(Script::from_name("Ital"), Some(Script::OldItalic));
+        assert_eq!(Script::from_name("OldItalic"), None);
+        assert_eq!(Script::NagMundari.name(), "Nag_Mundari");
+        assert_eq!(Script::NagMundari.short_name(), "Nagm");
+        assert_eq!(Script::Unknown.short_name(), "Zzzz");
+        
+        assert_eq!(general_category_by_name("Lu"), Some(UPPERCASE_LETTER));
+        assert_eq!(general_category_by_name("Cased_Letter"), Some(CASED_LETTER));
+        assert_eq!(general_category_by_name("Z"), Some(SEPARATOR));
+        assert_eq!(general_category_by_name("Seperator"), None);
+        assert_eq!(general_category_name(SPACE_SEPARATOR), Some("Zs"));
+        assert_eq!(general_category_name(PUNCTUATION), Some("P"));
+        assert_eq!(general_category_name(LETTER | NUMBER), None);
+        
+        // Every category has a bit of its own, and the groups are made of them.
+        let all = OTHER | LETTER | MARK | NUMBER | PUNCTUATION | SYMBOL | SEPARATOR;
+        assert_eq!(all.count_ones(), 30);
+        assert!(CASED_LETTER.subset_of(LETTER));
+        
+        // The bits stay where they have always been, from Lu to Cn.
+        assert_eq!((UPPERCASE_LETTER, OTHER_SYMBOL, UNASSIGNED), (1 << 0, 1 << 21, 1 << 29));
+    }
+    
+    #[test]
+    fn replaces_like_from_utf8_lossy() {
+        fn check(buf: &[u8]) {
+            let expected = String::from_utf8_lossy(buf);
+            
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            let mut decoded = String::new();
+            while let Some(c) = iter.next_char() { decoded.push(c); }
+            assert_eq!(decoded, expected);
+            
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            let mut decoded = String::new();
+            while let Some((c, _)) = iter.next_char_and_category() { decoded.push(c); }
+            assert_eq!(decoded, expected);
+            
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            let mut decoded = String::new();
+            while let Some((c, _)) = iter.next_char_and_script() { decoded.push(c); }
+            assert_eq!(decoded, expected);
+            
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            let mut decoded = String::new();
+            while let Some((c, _)) = iter.next_char_and_script_extensions() { decoded.push(c); }
+            assert_eq!(decoded, expected);
+            
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            let mut decoded = String::new();
+            while let Some((c, _, _)) = iter.next_char_and_properties() { decoded.push(c); }
+            assert_eq!(decoded, expected);
+        }
+        
+        // One byte of each class the decoder tells apart: ASCII, the three
+        // kinds of continuation bytes, C0 and F5 which are never valid, and the
+        // leading bytes with and without extra checks on the second byte.
+        let bytes = [0x61, 0x80, 0x90, 0xA0, 0xC0, 0xF5, 0xC2, 0xE0, 0xE1, 0xED, 0xF0, 0xF1, 0xF4];
+        for &a in &bytes {
+            check(&[a]);
+            for &b in &bytes {
+                check(&[a, b]);
+                for &c in &bytes {
+                    check(&[a, b, c]);
+                    for &d in &bytes { check(&[a, b, c, d]); }
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn reports_the_same_errors_when_interleaving_directions() {
+        fn interleaved(buf: &[u8], variant: Utf8Variant) -> Vec<Utf8Error> {
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            iter.set_variant(variant);
+            
+            let (mut errors, mut back) = (Vec::new(), false);
+            loop {
+                let c = if back { iter.prev_char() } else { iter.next_char() };
+                if c.is_none() { break; }
+                if let Err(e) = iter.status() {
+                    if errors.last() != Some(&e) { errors.push(e); }
+                }
+                back = !back;
+            }
+            
+            errors.sort_by_key(|e| e.offset());
+            errors
+        }
+        
+        fn forward(buf: &[u8], variant: Utf8Variant) -> Vec<Utf8Error> {
+            let mut iter = decode_utf8(buf);
+            iter.set_error_mode(ErrorMode::Replace);
+            iter.set_variant(variant);
+            
+            let mut errors = Vec::new();
+            while iter.next_char().is_some() {
+                if let Err(e) = iter.status() {
+                    if errors.last() != Some(&e) { errors.push(e); }
+                }
+            }
+            errors
+        }
+        
+        let kinds = |errors: Vec<Utf8Error>| errors.iter().map(|e| e.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds(interleaved(b"\xF4\xB2", Utf8Variant::Standard)),
+            [Utf8ErrorKind::OutOfCharacterRange, Utf8ErrorKind::NotALeadingByte]);
+        assert_eq!(kinds(interleaved(b"\x98\xE0\x90", Utf8Variant::Standard)),
+            [Utf8ErrorKind::NotALeadingByte, Utf8ErrorKind::OverlongEncoding,
+            Utf8ErrorKind::NotALeadingByte]);
+        
+        // Every short combination of bytes from each class, plus surrogate pairs.
+        let bytes = [0x41, 0x80, 0x90, 0xA0, 0xB0, 0xBF, 0xC0, 0xC2, 0xE0, 0xED, 0xEF, 0xF0, 0xF4, 0xF5];
+        let mut inputs: Vec<Vec<u8>> = vec![
+            b"\xED\xA0\xBD\xED\xB8\x80".to_vec(), b"\xED\xA0\xBD\xED\xB8".to_vec(),
+            b"\x41\xED\xB8\x80\xED\xA0\xBD".to_vec(), b"\xC0\x80\xC0".to_vec(),
+        ];
+        for len in 1..=4 {
+            for mut x in 0..bytes.len().pow(len) {
+                let mut buf = Vec::new();
+                for _ in 0..len { buf.push(bytes[x % bytes.len()]); x /= bytes.len(); }
+                inputs.push(buf);
+            }
+        }
+        
+        for buf in inputs.iter() {
+            for &variant in &[Utf8Variant::Standard, Utf8Variant::Wtf8, Utf8Variant::Cesu8,
+                Utf8Variant::ModifiedUtf8]
+            {
+                assert_eq!(interleaved(buf, variant), forward(buf, variant));
+            }
+        }
+    }
+    
+    #[test]
+    fn validates_marked_strings_after_variants() {
+        let mut iter = decode_utf8(b"\xED\xA0\x80");
+        iter.set_variant(Utf8Variant::Wtf8);
+        let mark = iter.mark();
+        assert_eq!(iter.next_code_point(), Some(0xD800));
+        iter.set_variant(Utf8Variant::Standard);
+        let e = iter.try_get_marked_string(mark).unwrap_err();
+        assert_eq!((e.kind(), e.offset()), (Utf8ErrorKind::SurrogateCharacter, 0));
+        
+        let mut iter = decode_utf8(b"a\xC0\x80b");
+        iter.set_variant(Utf8Variant::ModifiedUtf8);
+        let mark = iter.mark();
+        assert_eq!(iter.chars().collect::<String>(), "a\0b");
+        iter.set_variant(Utf8Variant::Standard);
+        assert!(iter.try_get_marked_string(mark).is_err());
+        
+        // A decoder that only ever decoded standard UTF-8 still returns its input.
+        let mut iter = decode_utf8(b"abc");
+        let mark = iter.mark();
+        iter.set_variant(Utf8Variant::Standard);
+        assert_eq!(iter.chars().count(), 3);
+        assert_eq!(iter.try_get_marked_string(mark), Ok("abc"));
+    }
+    
+    #[test]
+    fn size_hint_bounds_the_count() {
+        let inputs: [&[u8]; 5] = [
+            b"\xED\xA0\xBD\xED\xB8\x80", b"\xF0\x9F\x98\x80\xF0\x9F\x98\x80", b"\xC0\x80\xC0\x80",
+            b"\xED\xA0\xBD\xED\xB8\x80\xED\xA0\xBD\xED\xB8\x80abc", b"\xFF\xFE",
+        ];
+        for input in inputs.iter() {
+            for &variant in &[Utf8Variant::Standard, Utf8Variant::Wtf8, Utf8Variant::Cesu8,
+                Utf8Variant::ModifiedUtf8]
+            {
+                for &mode in &[ErrorMode::Report, ErrorMode::Replace, ErrorMode::Escape] {
+                    let mut iter = decode_utf8(input);
+                    iter.set_variant(variant);
+                    iter.set_error_mode(mode);
+                    let (lower, upper) = iter.chars().size_hint();
+                    let count = iter.chars().fold(0, |n, _| n + 1);
+                    assert!(lower <= count && count <= upper.unwrap());
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn validates_marked_strings_across_decoders() {
+        let input = b"\xED\xA0\x80a";
+        let mut wtf8 = decode_utf8(input);
+        wtf8.set_variant(Utf8Variant::Wtf8);
+        assert_eq!(wtf8.next_code_point(), Some(0xD800));
+        wtf8.set_variant(Utf8Variant::Standard);
+        let after_surrogate = wtf8.mark();
+        
+        let mut iter = decode_utf8(input);
+        let start = iter.mark();
+        iter.reset_to(after_surrogate);
+        assert_eq!(iter.next_char(), Some('a'));
+        assert!(iter.try_get_marked_string(start).is_err());
+    }
+    
+    #[test]
+    #[should_panic(expected = "different variant")]
+    fn rejects_marks_from_other_variants() {
+        let input = b"\xED\xA0\xBD\xED\xB8\x80";
+        let mut cesu8 = decode_utf8(input);
+        cesu8.set_variant(Utf8Variant::Cesu8);
+        cesu8.next_char();
+        
+        let mut iter = decode_utf8(input);
+        iter.reset_to(cesu8.mark());
+    }
+    
+    #[test]
+    #[should_panic(expected = "different input")]
+    fn rejects_marks_from_other_input() {
+        let input = b"abc";
+        let mut whole = decode_utf8(input);
+        whole.next_char();
+        
+        let mut iter = decode_utf8(&input[1..]);
+        iter.reset_to(whole.mark());
+    }
+    
+    #[test]
+    #[should_panic(expected = "different input")]
+    fn rejects_marks_from_overlapping_input() {
+        let input = "é".as_bytes();
+        let tail = decode_utf8(&input[1..]);
+        
+        let mut iter = decode_utf8(input);
+        assert_eq!(iter.next_char(), Some('é'));
+        let _ = iter.try_get_marked_string(tail.mark());
+    }
 }